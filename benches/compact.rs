@@ -0,0 +1,225 @@
+use criterion::{
+	black_box, criterion_group, criterion_main, measurement::WallTime, BatchSize, BenchmarkGroup,
+	Criterion, Throughput,
+};
+use list::compact::Compact;
+
+const ELEMENT_COUNT: usize = 128;
+
+fn bench_push(group: &mut BenchmarkGroup<'_, WallTime>) {
+	group.bench_function("push", |b| {
+		b.iter_batched(
+			Compact::<usize, ELEMENT_COUNT>::new,
+			|mut compact| {
+				for index in black_box(0..ELEMENT_COUNT) {
+					compact.push(index);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_insert(group: &mut BenchmarkGroup<'_, WallTime>) {
+	group.bench_function("insert", |b| {
+		b.iter_batched(
+			Compact::<usize, ELEMENT_COUNT>::new,
+			|mut compact| {
+				for index in black_box(0..ELEMENT_COUNT) {
+					let len = black_box(0);
+
+					compact.insert(len, index);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_insert_first(group: &mut BenchmarkGroup<'_, WallTime>) {
+	group.bench_function("insert_first", |b| {
+		b.iter_batched(
+			Compact::<usize, ELEMENT_COUNT>::new,
+			|mut compact| {
+				for index in black_box(0..ELEMENT_COUNT) {
+					compact.insert(0, index);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_insert_last(group: &mut BenchmarkGroup<'_, WallTime>) {
+	group.bench_function("insert_last", |b| {
+		b.iter_batched(
+			Compact::<usize, ELEMENT_COUNT>::new,
+			|mut compact| {
+				for index in black_box(0..ELEMENT_COUNT) {
+					let len = compact.len();
+
+					compact.insert(len, index);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_pop(group: &mut BenchmarkGroup<'_, WallTime>, compact: &Compact<usize, ELEMENT_COUNT>) {
+	group.bench_function("pop", |b| {
+		b.iter_batched(
+			|| compact.clone(),
+			|mut compact| {
+				for _ in black_box(0..ELEMENT_COUNT) {
+					let value = compact.pop().expect("Failed to pop");
+
+					black_box(value);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_remove(group: &mut BenchmarkGroup<'_, WallTime>, compact: &Compact<usize, ELEMENT_COUNT>) {
+	group.bench_function("remove", |b| {
+		b.iter_batched(
+			|| compact.clone(),
+			|mut compact| {
+				for _ in black_box(0..ELEMENT_COUNT) {
+					let len = black_box(0);
+					let value = compact.remove(len);
+
+					black_box(value);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_remove_first(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	compact: &Compact<usize, ELEMENT_COUNT>,
+) {
+	group.bench_function("remove_first", |b| {
+		b.iter_batched(
+			|| compact.clone(),
+			|mut compact| {
+				for _ in black_box(0..ELEMENT_COUNT) {
+					let value = compact.remove(0);
+
+					black_box(value);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_remove_last(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	compact: &Compact<usize, ELEMENT_COUNT>,
+) {
+	group.bench_function("remove_last", |b| {
+		b.iter_batched(
+			|| compact.clone(),
+			|mut compact| {
+				for _ in black_box(0..ELEMENT_COUNT) {
+					let len = compact.len() - 1;
+					let value = compact.remove(len);
+
+					black_box(value);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_swap_remove(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	compact: &Compact<usize, ELEMENT_COUNT>,
+) {
+	group.bench_function("swap_remove", |b| {
+		b.iter_batched(
+			|| compact.clone(),
+			|mut compact| {
+				for _ in black_box(0..ELEMENT_COUNT) {
+					let len = black_box(0);
+					let value = compact.swap_remove(len);
+
+					black_box(value);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_swap_remove_last(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	compact: &Compact<usize, ELEMENT_COUNT>,
+) {
+	group.bench_function("swap_remove_last", |b| {
+		b.iter_batched(
+			|| compact.clone(),
+			|mut compact| {
+				for _ in black_box(0..ELEMENT_COUNT) {
+					let len = compact.len() - 1;
+					let value = compact.swap_remove(len);
+
+					black_box(value);
+				}
+
+				compact
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn benchmark_compact(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Compact");
+	let elements = u64::try_from(ELEMENT_COUNT).unwrap();
+
+	group.throughput(Throughput::Elements(elements));
+
+	bench_push(&mut group);
+	bench_insert(&mut group);
+	bench_insert_first(&mut group);
+	bench_insert_last(&mut group);
+
+	let mut compact = Compact::<usize, ELEMENT_COUNT>::new();
+
+	compact.extend(0..ELEMENT_COUNT);
+
+	bench_pop(&mut group, &compact);
+	bench_remove(&mut group, &compact);
+	bench_remove_first(&mut group, &compact);
+	bench_remove_last(&mut group, &compact);
+	bench_swap_remove(&mut group, &compact);
+	bench_swap_remove_last(&mut group, &compact);
+}
+
+criterion_group!(benches, benchmark_compact);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use criterion::{
+	black_box, criterion_group, criterion_main, measurement::WallTime, BatchSize, BenchmarkGroup,
+	Criterion, Throughput,
+};
+use list::fixed::Fixed;
+
+const ELEMENT_COUNT: usize = 128;
+
+fn bench_fixed(group: &mut BenchmarkGroup<'_, WallTime>, fixed: &Fixed<usize, ELEMENT_COUNT>) {
+	group.bench_function("fixed", |b| {
+		b.iter_batched(
+			|| fixed.clone(),
+			|mut fixed| {
+				fixed.retain(|item| black_box(item % 3 != 0));
+
+				fixed
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn bench_vec(group: &mut BenchmarkGroup<'_, WallTime>, vec: &[usize]) {
+	group.bench_function("vec", |b| {
+		b.iter_batched(
+			|| vec.to_vec(),
+			|mut vec| {
+				vec.retain(|item| black_box(item % 3 != 0));
+
+				vec
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn benchmark_retain(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Retain");
+	let elements = u64::try_from(ELEMENT_COUNT).unwrap();
+
+	group.throughput(Throughput::Elements(elements));
+
+	let fixed = Fixed::<usize, ELEMENT_COUNT>::from_iter(0..ELEMENT_COUNT);
+	let vec = Vec::from_iter(0..ELEMENT_COUNT);
+
+	bench_fixed(&mut group, &fixed);
+	bench_vec(&mut group, &vec);
+}
+
+criterion_group!(benches, benchmark_retain);
+criterion_main!(benches);
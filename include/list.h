@@ -0,0 +1,39 @@
+/*
+ * Layouts of the FFI-stable list types of the `list` crate.
+ *
+ * The crate exports this file as `list::fixed::C_HEADER`, so a build script
+ * can write it next to the C sources that use it.
+ */
+
+#ifndef LIST_H
+#define LIST_H
+
+#include <stddef.h>
+
+/*
+ * A list of at most N elements of type T, laid out like `FixedFfi<T, N>`.
+ *
+ * The first `len` elements of `data` are initialized, and `len` is never
+ * greater than N.
+ */
+#define LIST_FIXED(T, N) \
+	struct { \
+		size_t len; \
+		T data[N]; \
+	}
+
+/*
+ * The pointer, length and capacity of a list buffer, laid out like
+ * `RawParts<T>`.
+ *
+ * The first `len` elements at `ptr` are initialized, and `len` is never
+ * greater than `cap`.
+ */
+#define LIST_RAW_PARTS(T) \
+	struct { \
+		T *ptr; \
+		size_t len; \
+		size_t cap; \
+	}
+
+#endif
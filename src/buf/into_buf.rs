@@ -0,0 +1,70 @@
+use bytes::Buf;
+
+/// A [`Buf`] that takes ownership of a list of bytes and reads it from the
+/// front.
+///
+/// Small frames can be encoded into a [`Fixed`] or [`Resizable`] and handed
+/// to code expecting a [`Buf`] without copying them into a `BytesMut`.
+///
+/// [`Fixed`]: crate::fixed::Fixed
+/// [`Resizable`]: crate::resizable::Resizable
+#[derive(Clone, Debug, Default)]
+pub struct IntoBuf<L> {
+	list: L,
+	position: usize,
+}
+
+impl<L> IntoBuf<L> {
+	/// Creates a buffer reading `list` from its first byte.
+	#[inline]
+	#[must_use]
+	pub const fn new(list: L) -> Self {
+		Self { list, position: 0 }
+	}
+
+	/// Returns the number of bytes already read.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns a reference to the underlying list.
+	#[inline]
+	#[must_use]
+	pub const fn get_ref(&self) -> &L {
+		&self.list
+	}
+
+	/// Consumes the buffer, returning the underlying list with every byte,
+	/// read or not.
+	#[inline]
+	#[must_use]
+	pub fn into_inner(self) -> L {
+		self.list
+	}
+}
+
+impl<L: AsRef<[u8]>> Buf for IntoBuf<L> {
+	#[inline]
+	fn remaining(&self) -> usize {
+		self.list.as_ref().len() - self.position
+	}
+
+	#[inline]
+	fn chunk(&self) -> &[u8] {
+		&self.list.as_ref()[self.position..]
+	}
+
+	#[inline]
+	fn advance(&mut self, cnt: usize) {
+		let remaining = self.remaining();
+
+		assert!(
+			cnt <= remaining,
+			"advance out of bounds: the len is {remaining} but advancing by {cnt}"
+		);
+
+		self.position += cnt;
+	}
+}
@@ -0,0 +1,8 @@
+//! Reading from lists of bytes through [`bytes::Buf`].
+
+#[cfg(test)]
+mod test;
+
+mod into_buf;
+
+pub use into_buf::IntoBuf;
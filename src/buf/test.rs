@@ -0,0 +1,30 @@
+use bytes::{Buf, BufMut};
+
+use crate::{buf::IntoBuf, fixed::Fixed};
+
+#[test]
+fn test_into_buf() {
+	let mut fixed = Fixed::<u8, 8>::new();
+
+	fixed.put_u16(0x0102);
+	fixed.put_slice(b"abc");
+
+	let mut buf = IntoBuf::new(fixed);
+
+	assert_eq!(buf.remaining(), 5);
+	assert_eq!(buf.get_u16(), 0x0102);
+	assert_eq!(buf.chunk(), b"abc");
+
+	buf.advance(1);
+
+	assert_eq!(buf.position(), 3);
+	assert_eq!(buf.copy_to_bytes(2).as_ref(), b"bc");
+	assert!(!buf.has_remaining());
+	assert_eq!(buf.into_inner().len(), 5);
+}
+
+#[test]
+#[should_panic = "advance out of bounds"]
+fn test_into_buf_advance() {
+	IntoBuf::new([1, 2]).advance(3);
+}
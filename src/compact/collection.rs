@@ -0,0 +1,927 @@
+use core::{
+	hint::select_unpredictable,
+	mem::{ManuallyDrop, MaybeUninit},
+	ops::{Deref, DerefMut},
+	ptr::NonNull,
+};
+
+use allocator_api2::{
+	alloc::{Allocator, Global},
+	collections::TryReserveError,
+	vec::Vec,
+};
+
+use crate::{
+	fixed::{Fixed, FixedSlice, Len},
+	resizable::{
+		check_alloc, reserve_heap, try_check_alloc, try_reserve_heap, IntoIter, Parts, Resizable,
+	},
+	traits::{List, Push},
+};
+
+#[repr(C)]
+struct Heap<T> {
+	ptr: NonNull<T>,
+	len: usize,
+}
+
+impl<T> Clone for Heap<T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for Heap<T> {}
+
+union Data<T, const N: usize> {
+	inline: ManuallyDrop<[MaybeUninit<T>; N]>,
+	heap: Heap<T>,
+}
+
+/// A contiguous resizable list of elements of type `T`, stored in a union of
+/// inline and heap storage.
+///
+/// The `capacity` word holds the length while the elements are inline and the
+/// heap capacity once they have spilled, so no separate discriminant is stored
+/// and the pointer and length are selected without branching.
+pub struct Compact<T, const N: usize, A: Allocator = Global> {
+	capacity: usize,
+	data: Data<T, N>,
+	alloc: A,
+}
+
+// SAFETY: The list owns its elements, whether they are inline or on the heap.
+unsafe impl<T: Send, const N: usize, A: Allocator + Send> Send for Compact<T, N, A> {}
+
+// SAFETY: The list only hands out shared references to its elements from `&self`.
+unsafe impl<T: Sync, const N: usize, A: Allocator + Sync> Sync for Compact<T, N, A> {}
+
+impl<T, const N: usize> Compact<T, N> {
+	/// Constructs a new, empty `Compact<T, N>`.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self::new_in(Global)
+	}
+
+	/// Constructs a new, empty `Compact<T, N>` with at least `capacity` capacity.
+	#[inline]
+	#[must_use]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity_in(capacity, Global)
+	}
+}
+
+impl<T, const N: usize, A: Allocator> Compact<T, N, A> {
+	/// Constructs a new, empty `Compact<T, N, A>` with the provided allocator.
+	#[inline]
+	#[must_use]
+	pub const fn new_in(alloc: A) -> Self {
+		Self {
+			capacity: 0,
+			data: Data {
+				inline: ManuallyDrop::new(unsafe { MaybeUninit::uninit().assume_init() }),
+			},
+			alloc,
+		}
+	}
+
+	/// Constructs a new, empty `Compact<T, N, A>` with at least `capacity`
+	/// capacity with the provided allocator.
+	#[inline]
+	#[must_use]
+	pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+		let mut result = Self::new_in(alloc);
+
+		result.reserve_exact(capacity);
+
+		result
+	}
+
+	/// Returns a reference to the underlying allocator.
+	#[inline]
+	#[must_use]
+	pub const fn allocator(&self) -> &A {
+		&self.alloc
+	}
+
+	/// Returns `true` if the elements have been moved to the heap.
+	#[inline]
+	#[must_use]
+	pub const fn spilled(&self) -> bool {
+		self.capacity > N
+	}
+
+	/// Returns the total number of elements the list can hold.
+	#[inline]
+	#[must_use]
+	pub fn capacity(&self) -> usize {
+		// The inline length is never greater than `N`.
+		self.capacity.max(N)
+	}
+
+	/// Returns the number of elements in the list.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		// SAFETY: The heap length is only selected when the list has spilled,
+		// in which case it is initialized.
+		unsafe {
+			let heap = core::ptr::addr_of!(self.data.heap.len)
+				.cast::<MaybeUninit<usize>>()
+				.read();
+
+			select_unpredictable(self.spilled(), heap, MaybeUninit::new(self.capacity))
+				.assume_init()
+		}
+	}
+
+	/// Returns `true` if the list contains no elements.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns a pointer to the first element of the list.
+	#[inline]
+	#[must_use]
+	pub fn as_ptr(&self) -> *const T {
+		// SAFETY: The heap pointer is only selected when the list has spilled,
+		// in which case it is initialized.
+		unsafe {
+			let inline = core::ptr::addr_of!(self.data.inline).cast::<T>();
+			let heap = core::ptr::addr_of!(self.data.heap.ptr)
+				.cast::<MaybeUninit<*const T>>()
+				.read();
+
+			select_unpredictable(self.spilled(), heap, MaybeUninit::new(inline)).assume_init()
+		}
+	}
+
+	/// Returns a mutable pointer to the first element of the list.
+	#[inline]
+	#[must_use]
+	pub fn as_mut_ptr(&mut self) -> *mut T {
+		let spilled = self.spilled();
+
+		// SAFETY: The heap pointer is only selected when the list has spilled,
+		// in which case it is initialized.
+		unsafe {
+			let inline = core::ptr::addr_of_mut!(self.data.inline).cast::<T>();
+			let heap = core::ptr::addr_of!(self.data.heap.ptr)
+				.cast::<MaybeUninit<*mut T>>()
+				.read();
+
+			select_unpredictable(spilled, heap, MaybeUninit::new(inline)).assume_init()
+		}
+	}
+
+	/// Extracts a slice containing the entire list.
+	#[inline]
+	#[must_use]
+	pub fn as_slice(&self) -> &[T] {
+		// SAFETY: The elements in `0..len` are always initialized.
+		unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
+	}
+
+	/// Extracts a mutable slice of the entire list.
+	#[inline]
+	#[must_use]
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		let len = self.len();
+
+		// SAFETY: The elements in `0..len` are always initialized.
+		unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), len) }
+	}
+
+	/// Sets the length of the list without dropping or initializing elements.
+	///
+	/// # Safety
+	///
+	/// `len` must not exceed the capacity and the elements in `0..len` must be
+	/// initialized.
+	#[inline]
+	unsafe fn set_len(&mut self, len: usize) {
+		if self.spilled() {
+			self.data.heap.len = len;
+		} else {
+			self.capacity = len;
+		}
+	}
+
+	/// Runs `f` on the heap elements as a `Vec`, writing its parts back to the
+	/// list afterwards even if `f` panics.
+	///
+	/// # Safety
+	///
+	/// The list must have spilled to the heap.
+	unsafe fn with_heap<R>(&mut self, f: impl FnOnce(&mut Vec<T, &A>) -> R) -> R {
+		struct Guard<'a, T, A: Allocator> {
+			vec: ManuallyDrop<Vec<T, &'a A>>,
+			capacity: &'a mut usize,
+			heap: &'a mut Heap<T>,
+		}
+
+		impl<T, A: Allocator> Drop for Guard<'_, T, A> {
+			fn drop(&mut self) {
+				*self.capacity = self.vec.capacity();
+				*self.heap = Heap {
+					// SAFETY: A `Vec` with spilled capacity is never dangling.
+					ptr: unsafe { NonNull::new_unchecked(self.vec.as_mut_ptr()) },
+					len: self.vec.len(),
+				};
+			}
+		}
+
+		let heap = unsafe { &mut self.data.heap };
+		let vec = unsafe {
+			Vec::from_raw_parts_in(heap.ptr.as_ptr(), heap.len, self.capacity, &self.alloc)
+		};
+		let mut guard = Guard {
+			vec: ManuallyDrop::new(vec),
+			capacity: &mut self.capacity,
+			heap,
+		};
+
+		f(&mut guard.vec)
+	}
+
+	/// Moves the inline elements to a heap allocation with room for at least
+	/// `required` elements, or `capacity` if larger.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn spill(&mut self, required: usize, capacity: usize) {
+		#[cfg(feature = "spill-stats")]
+		crate::spill::record::<T>(N, self.len(), required);
+
+		check_alloc();
+
+		let capacity = required.max(capacity).max(N + 1);
+		let (ptr, _, capacity, _) =
+			Vec::<T, &A>::with_capacity_in(capacity, &self.alloc).into_raw_parts_with_alloc();
+
+		// SAFETY: The new allocation is empty and has room for more than `N`
+		// elements.
+		unsafe { self.move_to_heap(ptr, capacity) };
+	}
+
+	/// Tries to move the inline elements to a heap allocation with room for at
+	/// least `required` elements, or `capacity` if larger, leaving the list
+	/// unchanged on failure.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn try_spill(&mut self, required: usize, capacity: usize) -> Result<(), TryReserveError> {
+		#[cfg(feature = "spill-stats")]
+		crate::spill::record::<T>(N, self.len(), required);
+
+		try_check_alloc()?;
+
+		let capacity = required.max(capacity).max(N + 1);
+		let mut vec = Vec::<T, &A>::new_in(&self.alloc);
+
+		vec.try_reserve_exact(capacity)?;
+
+		let (ptr, _, capacity, _) = vec.into_raw_parts_with_alloc();
+
+		// SAFETY: The new allocation is empty and has room for more than `N`
+		// elements.
+		unsafe { self.move_to_heap(ptr, capacity) };
+
+		Ok(())
+	}
+
+	/// Moves the inline elements to the start of an empty heap buffer.
+	///
+	/// With `zeroize` enabled the inline buffer is wiped once they are moved.
+	///
+	/// # Safety
+	///
+	/// The list must be inline, and `ptr` must have been allocated through the
+	/// allocator of the list for `capacity` elements, which is more than `N`.
+	unsafe fn move_to_heap(&mut self, ptr: *mut T, capacity: usize) {
+		debug_assert!(capacity > N, "heap buffer too small to spill into");
+
+		let len = self.len();
+
+		unsafe {
+			ptr.copy_from_nonoverlapping(self.as_ptr(), len);
+
+			#[cfg(feature = "zeroize")]
+			zeroize::Zeroize::zeroize(&mut *self.data.inline);
+
+			self.capacity = capacity;
+			self.data.heap = Heap {
+				// SAFETY: A `Vec` with spilled capacity is never dangling.
+				ptr: NonNull::new_unchecked(ptr),
+				len,
+			};
+		}
+	}
+
+	/// Grows a spilled list to fit `additional` more elements if it is full.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn reserve_spilled(&mut self, additional: usize, exact: bool) {
+		if self.capacity - self.len() >= additional {
+			return;
+		}
+
+		// Checked here, as the closure below would hide the caller's location.
+		check_alloc();
+
+		unsafe { self.with_heap(|vec| reserve_heap(vec, additional, exact)) };
+	}
+
+	/// Tries to grow a spilled list to fit `additional` more elements if it is
+	/// full.
+	#[inline]
+	fn try_reserve_spilled(
+		&mut self,
+		additional: usize,
+		exact: bool,
+	) -> Result<(), TryReserveError> {
+		if self.capacity - self.len() >= additional {
+			return Ok(());
+		}
+
+		unsafe { self.with_heap(|vec| try_reserve_heap(vec, additional, exact)) }
+	}
+
+	/// Makes room for at least one more element, growing the heap allocation
+	/// geometrically.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn grow(&mut self) {
+		if self.spilled() {
+			self.reserve_spilled(1, false);
+		} else {
+			self.spill(N + 1, N * 2);
+		}
+	}
+
+	/// Tries to make room for at least one more element, growing the heap
+	/// allocation geometrically.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn try_grow(&mut self) -> Result<(), TryReserveError> {
+		if self.spilled() {
+			self.try_reserve_spilled(1, false)
+		} else {
+			self.try_spill(N + 1, N * 2)
+		}
+	}
+
+	/// Reserves capacity for at least `additional` more elements to be inserted
+	/// in the given list.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn reserve(&mut self, additional: usize) {
+		if self.spilled() {
+			self.reserve_spilled(additional, false);
+		} else if N - self.capacity < additional {
+			let required = self
+				.capacity
+				.checked_add(additional)
+				.expect("capacity overflow");
+
+			self.spill(required, 0);
+		}
+	}
+
+	/// Reserves the minimum capacity for at least `additional` more elements to
+	/// be inserted in the given list.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn reserve_exact(&mut self, additional: usize) {
+		if self.spilled() {
+			self.reserve_spilled(additional, true);
+		} else if N - self.capacity < additional {
+			let required = self
+				.capacity
+				.checked_add(additional)
+				.expect("capacity overflow");
+
+			self.spill(required, 0);
+		}
+	}
+
+	/// Tries to reserve capacity for at least `additional` more elements to be
+	/// inserted in the given list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` if the capacity overflows or the allocator reports a
+	/// failure, in which case the list is left unchanged.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		if self.spilled() {
+			self.try_reserve_spilled(additional, false)
+		} else if N - self.capacity < additional {
+			self.try_spill(self.capacity.saturating_add(additional), 0)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Tries to reserve the minimum capacity for at least `additional` more
+	/// elements to be inserted in the given list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` if the capacity overflows or the allocator reports a
+	/// failure, in which case the list is left unchanged.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		if self.spilled() {
+			self.try_reserve_spilled(additional, true)
+		} else if N - self.capacity < additional {
+			self.try_spill(self.capacity.saturating_add(additional), 0)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Clears the list, removing all values.
+	#[inline]
+	pub fn clear(&mut self) {
+		let len = self.len();
+
+		// SAFETY: The elements before clearing are dropped once the length is
+		// reset. The pointer is taken afterwards so that `set_len` does not
+		// invalidate it.
+		unsafe {
+			self.set_len(0);
+
+			let slice: *mut [T] = core::ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), len);
+
+			slice.drop_in_place();
+		}
+	}
+
+	/// Shortens the list, keeping the first `len` elements and dropping the
+	/// rest. Does nothing if `len` is not less than the current length.
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		let old_len = self.len();
+
+		if len < old_len {
+			// SAFETY: `len` is less than `old_len`, so the tail is within the list.
+			unsafe {
+				self.set_len(len);
+
+				let tail = self.as_mut_ptr().add(len);
+				let slice: *mut [T] = core::ptr::slice_from_raw_parts_mut(tail, old_len - len);
+
+				slice.drop_in_place();
+			}
+		}
+	}
+
+	/// Retains only the elements specified by the predicate.
+	pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+		self.retain_mut(|item| f(item));
+	}
+
+	/// Retains only the elements specified by the predicate, passing a mutable reference to it.
+	pub fn retain_mut(&mut self, f: impl FnMut(&mut T) -> bool) {
+		if self.spilled() {
+			unsafe { self.with_heap(|vec| vec.retain_mut(f)) };
+		} else {
+			// SAFETY: The length is cleared first so that the elements are owned
+			// by the temporary list over the inline buffer, even if the predicate
+			// panics. The list is forgotten afterwards, leaving them in place.
+			unsafe {
+				let len = core::mem::take(&mut self.capacity);
+				let mut list = FixedSlice::new(&mut *self.data.inline);
+
+				list.set_len(len);
+				list.retain_mut(f);
+
+				self.capacity = list.len();
+
+				core::mem::forget(list);
+			}
+		}
+	}
+
+	/// Inserts an element at position `index` within the list, shifting all
+	/// elements after it to the right.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn insert(&mut self, index: usize, value: T) {
+		#[cold]
+		fn assert_failed(index: usize, len: usize) -> ! {
+			panic!("insertion index (is {index}) should be <= len (is {len})");
+		}
+
+		let len = self.len();
+
+		if index > len {
+			assert_failed(index, len);
+		}
+
+		if len == self.capacity() {
+			self.grow();
+		}
+
+		// SAFETY: `index` is less than or equal to `len`, and `len + 1` is within capacity.
+		unsafe {
+			let start = self.as_mut_ptr().add(index);
+
+			start.add(1).copy_from(start, len - index);
+			start.write(value);
+
+			self.set_len(len + 1);
+		}
+	}
+
+	/// Appends an element to the back of the list.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn push(&mut self, value: T) {
+		let len = self.len();
+
+		if len == self.capacity() {
+			self.grow();
+		}
+
+		// SAFETY: `len + 1` is within capacity.
+		unsafe {
+			self.as_mut_ptr().add(len).write(value);
+
+			self.set_len(len + 1);
+		}
+	}
+
+	/// Inserts an element at position `index` within the list, shifting all
+	/// elements after it to the right.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the inserted value if the index is out of bounds or
+	/// the list could not grow.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), T> {
+		let len = self.len();
+
+		if index > len || (len == self.capacity() && self.try_grow().is_err()) {
+			return Err(value);
+		}
+
+		self.insert(index, value);
+
+		Ok(())
+	}
+
+	/// Appends an element to the back of the list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the pushed value if the list could not grow.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_push(&mut self, value: T) -> Result<(), T> {
+		let len = self.len();
+
+		self.try_insert(len, value)
+	}
+
+	/// Removes and returns the element at position `index` within the list,
+	/// shifting all elements after it to the left.
+	#[inline]
+	pub fn remove(&mut self, index: usize) -> T {
+		#[cold]
+		fn assert_failed(index: usize, len: usize) -> ! {
+			panic!("removal index (is {index}) should be < len (is {len})");
+		}
+
+		let len = self.len();
+
+		if index >= len {
+			assert_failed(index, len);
+		}
+
+		// SAFETY: `index` is less than `len`.
+		unsafe {
+			let start = self.as_mut_ptr().add(index);
+			let value = start.read();
+
+			start.add(1).copy_to(start, len - index - 1);
+
+			self.set_len(len - 1);
+
+			value
+		}
+	}
+
+	/// Removes the last element from a list and returns it, or [`None`] if it
+	/// is empty.
+	#[inline]
+	pub fn pop(&mut self) -> Option<T> {
+		let last = self.len().checked_sub(1)?;
+
+		// SAFETY: `last` is less than the old length.
+		unsafe {
+			self.set_len(last);
+
+			Some(self.as_ptr().add(last).read())
+		}
+	}
+
+	/// Removes an element from the list and returns it.
+	///
+	/// The removed element is replaced by the last element of the list.
+	#[inline]
+	pub fn swap_remove(&mut self, index: usize) -> T {
+		#[cold]
+		fn assert_failed(index: usize, len: usize) -> ! {
+			panic!("swap_remove index (is {index}) should be < len (is {len})");
+		}
+
+		let len = self.len();
+
+		if index >= len {
+			assert_failed(index, len);
+		}
+
+		// SAFETY: Both `index` and `len - 1` are less than `len`.
+		unsafe {
+			let start = self.as_mut_ptr();
+			let value = start.add(index).read();
+
+			start.add(index).copy_from(start.add(len - 1), 1);
+
+			self.set_len(len - 1);
+
+			value
+		}
+	}
+}
+
+impl<T, const N: usize, A: Allocator> From<Resizable<T, N, A>> for Compact<T, N, A> {
+	#[inline]
+	fn from(list: Resizable<T, N, A>) -> Self {
+		match list.into_parts() {
+			Parts::Fixed(list, alloc) => {
+				let (inline, len) = list.into_raw_parts();
+
+				Self {
+					capacity: len.into(),
+					data: Data {
+						inline: ManuallyDrop::new(inline),
+					},
+					alloc,
+				}
+			}
+			Parts::Heap(list) => {
+				let mut list = ManuallyDrop::new(list);
+				let (ptr, len, capacity) = (list.as_mut_ptr(), list.len(), list.capacity());
+
+				// SAFETY: The allocator is moved out of a list that is never dropped.
+				let alloc = unsafe { core::ptr::read(list.allocator()) };
+
+				if capacity > N {
+					Self {
+						capacity,
+						data: Data {
+							heap: Heap {
+								// SAFETY: A `Vec` with spilled capacity is never dangling.
+								ptr: unsafe { NonNull::new_unchecked(ptr) },
+								len,
+							},
+						},
+						alloc,
+					}
+				} else {
+					let mut result = Self::new_in(alloc);
+
+					// SAFETY: `len` is at most `capacity`, which fits inline, and the
+					// elements are freed from the heap without being dropped.
+					unsafe {
+						result.as_mut_ptr().copy_from_nonoverlapping(ptr, len);
+						result.set_len(len);
+
+						#[cfg(feature = "zeroize")]
+						zeroize::Zeroize::zeroize(core::slice::from_raw_parts_mut(
+							ptr.cast::<MaybeUninit<T>>(),
+							capacity,
+						));
+
+						drop(Vec::from_raw_parts_in(ptr, 0, capacity, &result.alloc));
+					}
+
+					result
+				}
+			}
+		}
+	}
+}
+
+impl<T, const N: usize, A: Allocator> From<Compact<T, N, A>> for Resizable<T, N, A> {
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn from(mut list: Compact<T, N, A>) -> Self {
+		// An inline capacity above the largest length of `Fixed` only fits on
+		// the heap.
+		if !list.spilled() && N > Len::MAX {
+			list.spill(0, 0);
+		}
+
+		let mut list = ManuallyDrop::new(list);
+
+		// SAFETY: The allocator is moved out of a list that is never dropped.
+		let alloc = unsafe { core::ptr::read(&list.alloc) };
+
+		if list.spilled() {
+			let Heap { ptr, len } = unsafe { list.data.heap };
+			let heap = unsafe { Vec::from_raw_parts_in(ptr.as_ptr(), len, list.capacity, alloc) };
+
+			Self::Heap(heap)
+		} else {
+			// SAFETY: The inline length is at most `N`, which fits within a `u8`.
+			unsafe {
+				let inline = ManuallyDrop::take(&mut list.data.inline);
+
+				Self::Fixed(Fixed::from_raw_parts(inline, list.capacity as u8), alloc)
+			}
+		}
+	}
+}
+
+impl<T, const N: usize, A: Allocator> IntoIterator for Compact<T, N, A> {
+	type IntoIter = IntoIter<T, N, A>;
+	type Item = T;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		Resizable::from(self).into_iter()
+	}
+}
+
+impl<'a, T, const N: usize, A: Allocator> IntoIterator for &'a Compact<T, N, A> {
+	type IntoIter = core::slice::Iter<'a, T>;
+	type Item = &'a T;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_slice().iter()
+	}
+}
+
+impl<'a, T, const N: usize, A: Allocator> IntoIterator for &'a mut Compact<T, N, A> {
+	type IntoIter = core::slice::IterMut<'a, T>;
+	type Item = &'a mut T;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const N: usize, A: Allocator> List<T> for Compact<T, N, A> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+
+	#[inline]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	#[inline]
+	fn clear(&mut self) {
+		self.clear();
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: T) -> Result<(), T> {
+		self.try_push(value)
+	}
+
+	#[inline]
+	fn try_insert(&mut self, index: usize, value: T) -> Result<(), T> {
+		self.try_insert(index, value)
+	}
+
+	#[inline]
+	fn try_remove(&mut self, index: usize) -> Option<T> {
+		(index < self.len()).then(|| self.remove(index))
+	}
+
+	#[inline]
+	fn remove(&mut self, index: usize) -> T {
+		self.remove(index)
+	}
+
+	#[inline]
+	fn pop(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T, const N: usize, A: Allocator> Push<T> for Compact<T, N, A> {
+	#[inline]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional);
+	}
+
+	#[inline]
+	fn push(&mut self, value: T) {
+		self.push(value);
+	}
+
+	#[inline]
+	fn insert(&mut self, index: usize, value: T) {
+		self.insert(index, value);
+	}
+}
+
+impl<T, const N: usize, A: Allocator + Default> Default for Compact<T, N, A> {
+	#[inline]
+	fn default() -> Self {
+		Self::new_in(A::default())
+	}
+}
+
+impl<T, const N: usize, A: Allocator> Extend<T> for Compact<T, N, A> {
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		let iter = iter.into_iter();
+
+		self.reserve(iter.size_hint().0);
+
+		// A closure would hide the caller's location from `push`.
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<T, const N: usize, A: Allocator + Default> FromIterator<T> for Compact<T, N, A> {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut result = Self::default();
+
+		result.extend(iter);
+
+		result
+	}
+}
+
+impl<T: Clone, const N: usize, A: Allocator + Clone> Clone for Compact<T, N, A> {
+	#[inline]
+	fn clone(&self) -> Self {
+		let mut result = Self::with_capacity_in(self.len(), self.alloc.clone());
+
+		result.extend(self.iter().cloned());
+
+		result
+	}
+}
+
+impl<T, const N: usize, A: Allocator> Drop for Compact<T, N, A> {
+	#[inline]
+	fn drop(&mut self) {
+		if self.spilled() {
+			let Heap { ptr, len } = unsafe { self.data.heap };
+
+			drop(unsafe { Vec::from_raw_parts_in(ptr.as_ptr(), len, self.capacity, &self.alloc) });
+		} else {
+			self.clear();
+		}
+	}
+}
+
+impl<T, const N: usize, A: Allocator> Deref for Compact<T, N, A> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize, A: Allocator> DerefMut for Compact<T, N, A> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
+	}
+}
+
+impl<T: core::fmt::Debug, const N: usize, A: Allocator> core::fmt::Debug for Compact<T, N, A> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.as_slice().fmt(f)
+	}
+}
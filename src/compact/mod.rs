@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod test;
+
+mod collection;
+
+pub use collection::Compact;
+
+#[macro_export]
+macro_rules! compact {
+	() => (
+		$crate::compact::Compact::new()
+	);
+	($element:expr; $count:expr) => ({
+		let iter = ::core::iter::repeat_n($element, $count);
+
+		$crate::compact::Compact::from_iter(iter)
+	});
+	($($element:expr),+ $(,)?) => ({
+		let iter = [$($element),+];
+
+		$crate::compact::Compact::from_iter(iter)
+	});
+}
@@ -0,0 +1,236 @@
+use core::{alloc::Layout, ptr::NonNull};
+
+use alloc::boxed::Box;
+use allocator_api2::alloc::{AllocError, Allocator};
+
+use crate::{compact::Compact, resizable::Resizable};
+
+#[test]
+fn test_size() {
+	assert_eq!(
+		core::mem::size_of::<Compact<u64, 4>>(),
+		core::mem::size_of::<usize>() + core::mem::size_of::<[u64; 4]>()
+	);
+
+	assert!(core::mem::size_of::<Compact<u64, 4>>() <= core::mem::size_of::<Resizable<u64, 4>>());
+}
+
+#[test]
+fn test_push_elements() {
+	let mut compact = Compact::<u64, 2>::new();
+
+	assert_eq!(compact.len(), 0);
+	compact.push(1);
+
+	assert_eq!(compact.len(), 1);
+	compact.push(2);
+
+	assert_eq!(compact.len(), 2);
+	assert!(!compact.spilled());
+	compact.push(3);
+
+	assert_eq!(compact.len(), 3);
+	assert!(compact.spilled());
+	compact.push(4);
+
+	assert_eq!(compact.as_slice(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn test_insert_elements() {
+	let mut compact = Compact::<u64, 2>::new();
+
+	assert_eq!(compact.len(), 0);
+	compact.insert(0, 1);
+
+	assert_eq!(compact.len(), 1);
+	compact.insert(0, 2);
+
+	assert_eq!(compact.len(), 2);
+	compact.insert(1, 3);
+
+	assert_eq!(compact.len(), 3);
+	compact.insert(1, 4);
+
+	assert_eq!(compact.as_slice(), &[2, 4, 3, 1]);
+}
+
+#[test]
+fn test_pop_elements() {
+	let mut compact = Compact::<u64, 2>::new();
+
+	compact.push(1);
+	compact.push(2);
+	compact.push(3);
+	compact.push(4);
+
+	assert_eq!(compact.pop(), Some(4));
+	assert_eq!(compact.pop(), Some(3));
+	assert_eq!(compact.pop(), Some(2));
+	assert_eq!(compact.pop(), Some(1));
+	assert_eq!(compact.pop(), None);
+}
+
+#[test]
+fn test_remove_elements() {
+	let mut compact = Compact::<u64, 2>::new();
+
+	compact.push(1);
+	compact.push(2);
+	compact.push(3);
+	compact.push(4);
+
+	assert_eq!(compact.remove(1), 2);
+	assert_eq!(compact.swap_remove(0), 1);
+	assert_eq!(compact.remove(0), 4);
+	assert_eq!(compact.remove(0), 3);
+}
+
+#[test]
+fn test_retain() {
+	let mut compact = Compact::<u64, 4>::new();
+
+	compact.extend([1, 2, 3, 4]);
+	compact.retain(|item| item % 2 == 0);
+
+	assert_eq!(compact.as_slice(), &[2, 4]);
+
+	compact.extend([5, 6, 7, 8]);
+	compact.retain(|item| item % 2 == 0);
+
+	assert_eq!(compact.as_slice(), &[2, 4, 6, 8]);
+}
+
+#[test]
+fn test_clear() {
+	let mut compact = Compact::<Box<u64>, 24>::new();
+
+	for index in 0..128 {
+		compact.push(index.into());
+	}
+
+	assert_eq!(compact.len(), 128);
+
+	compact.clear();
+
+	assert!(compact.is_empty());
+}
+
+#[test]
+fn test_resizable_round_trip() {
+	let mut compact = Compact::<Box<u64>, 2>::new();
+
+	compact.push(1.into());
+
+	let resizable = Resizable::from(compact);
+
+	assert!(matches!(resizable, Resizable::Fixed(..)));
+
+	let mut compact = Compact::from(resizable);
+
+	compact.push(2.into());
+	compact.push(3.into());
+
+	let resizable = Resizable::from(compact);
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+	assert_eq!(resizable.as_slice(), &[1.into(), 2.into(), 3.into()]);
+}
+
+#[test]
+fn test_into_iter() {
+	let mut compact = Compact::<u64, 2>::new();
+
+	compact.push(1);
+	compact.push(2);
+	compact.push(3);
+	compact.push(4);
+
+	let mut iter = compact.into_iter();
+
+	assert_eq!(iter.next(), Some(1));
+	assert_eq!(iter.next_back(), Some(4));
+	assert_eq!(iter.next(), Some(2));
+	assert_eq!(iter.next_back(), Some(3));
+	assert_eq!(iter.next(), None);
+	assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+#[should_panic = "capacity overflow"]
+fn test_reserve_overflow() {
+	let mut compact = Compact::<u8, 16>::from_iter(0..10);
+
+	compact.reserve(usize::MAX - 5);
+}
+
+#[test]
+fn test_try_push_failing() {
+	struct Failing;
+
+	unsafe impl Allocator for Failing {
+		fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			Err(AllocError)
+		}
+
+		unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {}
+	}
+
+	let mut compact = Compact::<u64, 2, _>::new_in(Failing);
+
+	assert_eq!(compact.try_push(1), Ok(()));
+	assert_eq!(compact.try_insert(0, 2), Ok(()));
+	assert_eq!(compact.try_insert(3, 3), Err(3));
+	assert_eq!(compact.try_push(4), Err(4));
+	assert_eq!(compact.try_insert(0, 5), Err(5));
+	assert!(compact.try_reserve(1).is_err());
+
+	assert!(!compact.spilled());
+	assert_eq!(compact.as_slice(), &[2, 1]);
+}
+
+#[test]
+fn test_try_reserve() {
+	let mut compact = Compact::<u64, 2>::from_iter([1, 2]);
+
+	assert!(compact.try_reserve(usize::MAX).is_err());
+	assert!(compact.try_reserve_exact(usize::MAX).is_err());
+	assert!(!compact.spilled());
+
+	assert_eq!(compact.try_reserve(2), Ok(()));
+	assert!(compact.capacity() >= 4);
+	assert!(compact.try_reserve(usize::MAX).is_err());
+
+	assert_eq!(compact.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn test_large_inline() {
+	let mut compact = Compact::<u16, 300>::from_iter(0..300);
+
+	assert!(!compact.spilled());
+
+	compact.retain(|value| value % 2 == 0);
+
+	assert_eq!(compact.len(), 150);
+
+	let resizable = Resizable::from(compact);
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+	assert!(resizable.into_iter().eq((0..300).step_by(2)));
+}
+
+#[test]
+fn test_drop_inline() {
+	let mut compact = Compact::<Box<u8>, 16>::from_iter((0..10).map(Box::new));
+
+	compact.truncate(4);
+
+	assert_eq!(compact.as_slice(), &[0, 1, 2, 3].map(Box::new));
+
+	compact.clear();
+	compact.push(Box::new(4));
+
+	drop(compact);
+	drop(Compact::<u8, 16>::from_iter(0..10));
+}
@@ -0,0 +1,104 @@
+use core::mem::MaybeUninit;
+
+use super::{
+	collection::FixedIn,
+	len::Len,
+	storage::{InlineStorage, Storage},
+};
+
+/// A marker type whose alignment is applied to the buffer of an [`Aligned`]
+/// list.
+///
+/// This trait is sealed: it is implemented for [`Align16`], [`Align32`],
+/// [`Align64`] and [`Align128`] only.
+pub trait Alignment: Copy + sealed::Sealed {
+	/// The alignment in bytes.
+	const ALIGN: usize;
+}
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+macro_rules! alignments {
+	($($(#[$attr:meta])* $name:ident = $align:literal;)+) => {$(
+		$(#[$attr])*
+		#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+		#[repr(align($align))]
+		pub struct $name;
+
+		impl sealed::Sealed for $name {}
+
+		impl Alignment for $name {
+			const ALIGN: usize = $align;
+		}
+	)+};
+}
+
+alignments! {
+	/// Aligns a buffer to 16 bytes, the width of SSE and NEON registers.
+	Align16 = 16;
+	/// Aligns a buffer to 32 bytes, the width of AVX registers.
+	Align32 = 32;
+	/// Aligns a buffer to 64 bytes, the width of AVX-512 registers and of most
+	/// cache lines.
+	Align64 = 64;
+	/// Aligns a buffer to 128 bytes, the cache line size on some ARM cores.
+	Align128 = 128;
+}
+
+/// An inline array of `N` slots whose first slot is aligned to at least
+/// `Al::ALIGN` bytes.
+#[repr(C)]
+pub struct Aligned<T, const N: usize, Al: Alignment> {
+	_align: [Al; 0],
+	inner: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize, Al: Alignment> Aligned<T, N, Al> {
+	/// Wraps an array so that it is aligned to `Al::ALIGN` bytes.
+	#[inline]
+	#[must_use]
+	pub const fn new(inner: [MaybeUninit<T>; N]) -> Self {
+		Self { _align: [], inner }
+	}
+
+	/// Unwraps the array.
+	#[inline]
+	#[must_use]
+	pub const fn into_inner(self) -> [MaybeUninit<T>; N] {
+		self.inner
+	}
+}
+
+unsafe impl<T, const N: usize, Al: Alignment> Storage for Aligned<T, N, Al> {
+	type Item = T;
+	type Len = Len;
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		N
+	}
+
+	#[inline]
+	fn as_ptr(&self) -> *const T {
+		self.inner.as_ptr().cast()
+	}
+
+	#[inline]
+	fn as_mut_ptr(&mut self) -> *mut T {
+		self.inner.as_mut_ptr().cast()
+	}
+}
+
+unsafe impl<T, const N: usize, Al: Alignment> InlineStorage for Aligned<T, N, Al> {
+	const CAPACITY: usize = N;
+	const UNINIT: Self = Self::new([const { MaybeUninit::uninit() }; N]);
+}
+
+/// A contiguous fixed-size list of elements of type `T` whose buffer is
+/// aligned to `Al::ALIGN` bytes, for loading it into SIMD registers.
+///
+/// [`as_ptr`](FixedAligned::as_ptr) always returns a pointer aligned to at least
+/// `Al::ALIGN`.
+pub type FixedAligned<T, const N: usize, Al> = FixedIn<Aligned<T, N, Al>>;
@@ -0,0 +1,16 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::collection::Fixed;
+
+/// Generates at most `N` elements, leaving any further input unused.
+impl<'a, T: Arbitrary<'a>, const N: usize> Arbitrary<'a> for Fixed<T, N> {
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		u.arbitrary_iter()?.take(N).collect()
+	}
+
+	#[inline]
+	fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+		u.arbitrary_take_rest_iter()?.take(N).collect()
+	}
+}
@@ -0,0 +1,18 @@
+use core::mem::MaybeUninit;
+
+use alloc::boxed::Box;
+
+use super::collection::FixedIn;
+
+/// A contiguous list of elements of type `T` in a boxed buffer whose capacity
+/// is chosen at runtime and never changes.
+pub type FixedBox<T> = FixedIn<Box<[MaybeUninit<T>]>>;
+
+impl<T> FixedBox<T> {
+	/// Constructs a new, empty list with room for exactly `capacity` elements.
+	#[inline]
+	#[must_use]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self::from_storage(Box::new_uninit_slice(capacity))
+	}
+}
@@ -0,0 +1,45 @@
+use bytes::{buf::UninitSlice, BufMut};
+
+use crate::traits::List;
+
+use super::{collection::FixedIn, storage::Storage};
+
+/// Writes into the spare capacity of the list, which never grows.
+unsafe impl<S: Storage<Item = u8>> BufMut for FixedIn<S> {
+	#[inline]
+	fn remaining_mut(&self) -> usize {
+		self.capacity() - self.len()
+	}
+
+	#[inline]
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		let remaining = self.remaining_mut();
+
+		assert!(
+			cnt <= remaining,
+			"advance out of bounds: the len is {remaining} but advancing by {cnt}"
+		);
+
+		// SAFETY: The caller initialized the first `cnt` spare bytes, which fit
+		// in the capacity.
+		unsafe { self.set_len(self.len() + cnt) };
+	}
+
+	#[inline]
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		UninitSlice::uninit(self.spare_capacity_mut())
+	}
+
+	#[inline]
+	fn put_slice(&mut self, src: &[u8]) {
+		let remaining = self.remaining_mut();
+
+		assert!(
+			src.len() <= remaining,
+			"buffer overflow: the remaining capacity is {remaining} but writing {} bytes",
+			src.len()
+		);
+
+		self.write_bytes(src);
+	}
+}
@@ -0,0 +1,33 @@
+use core::mem::size_of;
+
+use bytemuck::{AnyBitPattern, Zeroable};
+
+use super::ffi::{CArray, FixedFfi};
+
+// The list is not `Pod`: its spare slots are uninitialized and its length must
+// stay within the capacity, so only the checked conversion below reads one
+// from bytes.
+
+unsafe impl<T, const N: usize> Zeroable for CArray<T, N> {}
+
+/// A zeroed list is empty.
+unsafe impl<T, const N: usize> Zeroable for FixedFfi<T, N> {}
+
+impl<T: AnyBitPattern, const N: usize> FixedFfi<T, N> {
+	/// Reinterprets bytes written by C code as a list.
+	///
+	/// Returns `None` if `bytes` is not exactly the size of the list, is
+	/// misaligned, or holds a length greater than `N`.
+	#[must_use]
+	pub fn from_bytes(bytes: &[u8]) -> Option<&Self> {
+		if bytes.len() != size_of::<Self>() || !bytes.as_ptr().cast::<Self>().is_aligned() {
+			return None;
+		}
+
+		let len: usize = bytemuck::pod_read_unaligned(&bytes[..size_of::<usize>()]);
+
+		// SAFETY: The size and alignment match, the length is at most the
+		// capacity, and every bit pattern is a valid `T`.
+		(len <= N).then(|| unsafe { &*bytes.as_ptr().cast::<Self>() })
+	}
+}
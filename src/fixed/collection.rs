@@ -1,29 +1,83 @@
-use core::mem::MaybeUninit;
+use core::{
+	mem::{ManuallyDrop, MaybeUninit},
+	ops::RangeBounds,
+	ptr::NonNull,
+};
 
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
-use super::into_iter::IntoIter;
+use crate::traits::List;
+
+use super::{
+	aligned::{Aligned, Alignment},
+	drain::{self, Drain},
+	ffi::CArray,
+	into_iter::IntoIterIn,
+	len::{Len, Length},
+	storage::{InlineStorage, Storage},
+};
+
+/// A contiguous fixed-capacity list of elements kept in the storage `S`.
+///
+/// The list logic is written once against [`Storage`], so every kind of buffer
+/// gets the same API. [`Fixed`] is the list over an inline array.
+///
+/// The storage kinds of the crate have inherent `len`, `capacity`, `is_empty`,
+/// `as_ptr` and `as_slice` methods. Lists over other storage reach them
+/// through [`List`], which must be in scope.
+///
+/// The length is laid out first and the storage after it, as in a C struct.
+/// Only [`FixedFfi`](super::FixedFfi) fixes the layout of both fields.
+#[repr(C)]
+#[cfg_attr(
+	feature = "zerocopy",
+	derive(zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+pub struct FixedIn<S: Storage> {
+	len: S::Len,
+	inner: S,
+}
 
 /// A contiguous fixed-size list of elements of type `T`.
-pub struct Fixed<T, const N: usize> {
-	len: u8,
-	inner: [MaybeUninit<T>; N],
-}
+pub type Fixed<T, const N: usize> = FixedIn<[MaybeUninit<T>; N]>;
 
-impl<T, const N: usize> Fixed<T, N> {
-	/// Constructs a new, empty `Fixed<T, N>`.
+impl<S: InlineStorage> FixedIn<S> {
+	/// Constructs a new, empty list over inline storage.
 	///
 	/// # Panics
 	///
-	/// Panics if the capacity of the list does not fit within a `u8`.
+	/// Panics if the capacity of the list is greater than 254.
 	#[inline]
 	#[must_use]
 	pub const fn new() -> Self {
-		assert!(N <= u8::MAX as usize, "`Fixed` capacity exceeds `u8`");
+		assert!(S::CAPACITY <= S::Len::MAX, "`Fixed` capacity exceeds 254");
+
+		Self {
+			inner: S::UNINIT,
+			len: S::Len::ZERO,
+		}
+	}
+}
+
+impl<T, const N: usize> Fixed<T, N> {
+	/// Creates a `Fixed<T, N>` directly from an array of `MaybeUninit<T>` and a length.
+	///
+	/// # Safety
+	///
+	/// `len` must be a valid length for the initialized part of the array.
+	///
+	/// # Panics
+	///
+	/// Panics if the capacity of the list is greater than 254.
+	#[inline]
+	pub const unsafe fn from_raw_parts(inner: [MaybeUninit<T>; N], len: u8) -> Self {
+		assert!(N <= Len::MAX, "`Fixed` capacity exceeds 254");
+		debug_assert!(len as usize <= N, "`len` must be at most the capacity");
 
 		Self {
-			inner: unsafe { MaybeUninit::uninit().assume_init() },
-			len: 0,
+			len: unsafe { Len::new_unchecked(len as usize) },
+			inner,
 		}
 	}
 
@@ -31,16 +85,16 @@ impl<T, const N: usize> Fixed<T, N> {
 	#[inline]
 	#[must_use]
 	pub const fn capacity(&self) -> usize {
-		self.inner.len()
+		N
 	}
 
 	/// Returns the number of elements in the list.
 	#[inline]
 	#[must_use]
 	pub const fn len(&self) -> usize {
-		let len = self.len as usize;
+		let len = self.len.get();
 
-		if len > self.capacity() {
+		if len > N {
 			// SAFETY: `len` is always a valid length for the array.
 			unsafe { core::hint::unreachable_unchecked() };
 		}
@@ -59,14 +113,7 @@ impl<T, const N: usize> Fixed<T, N> {
 	#[inline]
 	#[must_use]
 	pub const fn as_ptr(&self) -> *const T {
-		self.inner.as_ptr().cast()
-	}
-
-	/// Returns a mutable pointer to the first element of the list.
-	#[inline]
-	#[must_use]
-	pub fn as_mut_ptr(&mut self) -> *mut T {
-		self.inner.as_mut_ptr().cast()
+		<[MaybeUninit<T>]>::as_ptr(&self.inner).cast()
 	}
 
 	/// Extracts a slice containing the entire list.
@@ -77,25 +124,191 @@ impl<T, const N: usize> Fixed<T, N> {
 		unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
 	}
 
+	/// Decomposes a `Fixed<T, N>` into its raw components.
+	#[inline]
+	pub fn into_raw_parts(self) -> ([MaybeUninit<T>; N], u8) {
+		let (inner, len) = self.into_storage_parts();
+
+		(inner, len.get() as u8)
+	}
+}
+
+/// Implements the accessors that [`Fixed`] has as `const` functions for the
+/// other storage kinds of the crate. They cannot be implemented once for every
+/// storage, as that would overlap with those of [`Fixed`], so lists over other
+/// storage reach them through [`List`].
+macro_rules! impl_accessors {
+	($([$($generics:tt)*] $storage:ty),* $(,)?) => {$(
+		impl<$($generics)*> FixedIn<$storage> {
+			/// Returns the total number of elements the list can hold.
+			#[inline]
+			#[must_use]
+			pub fn capacity(&self) -> usize {
+				List::capacity(self)
+			}
+
+			/// Returns the number of elements in the list.
+			#[inline]
+			#[must_use]
+			pub fn len(&self) -> usize {
+				List::len(self)
+			}
+
+			/// Returns `true` if the list contains no elements.
+			#[inline]
+			#[must_use]
+			pub fn is_empty(&self) -> bool {
+				List::len(self) == 0
+			}
+
+			/// Returns a pointer to the first element of the list.
+			#[inline]
+			#[must_use]
+			pub fn as_ptr(&self) -> *const T {
+				self.storage_ptr()
+			}
+
+			/// Extracts a slice containing the entire list.
+			#[inline]
+			#[must_use]
+			pub fn as_slice(&self) -> &[T] {
+				self
+			}
+		}
+	)*};
+}
+
+impl_accessors! {
+	['a, T] &'a mut [MaybeUninit<T>],
+	[T, const N: usize, Al: Alignment] Aligned<T, N, Al>,
+	[T, const N: usize] CArray<T, N>,
+}
+
+#[cfg(feature = "alloc")]
+impl_accessors! {
+	[T] alloc::boxed::Box<[MaybeUninit<T>]>,
+}
+
+impl<S: Storage> FixedIn<S> {
+	/// Constructs a new, empty list over the given storage.
+	///
+	/// Any values already in the storage are ignored and never dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if the capacity of the storage does not fit the length type of
+	/// the storage.
+	#[inline]
+	#[must_use]
+	pub fn from_storage(inner: S) -> Self {
+		assert!(
+			inner.capacity() <= S::Len::MAX,
+			"storage capacity exceeds {}",
+			S::Len::MAX
+		);
+
+		Self {
+			len: S::Len::ZERO,
+			inner,
+		}
+	}
+
+	/// Drops the elements of the list and returns its storage.
+	#[inline]
+	#[must_use]
+	pub fn into_storage(mut self) -> S {
+		self.clear();
+
+		self.into_storage_parts().0
+	}
+
+	/// Moves the storage and length out without dropping any elements.
+	#[inline]
+	pub(super) fn into_storage_parts(self) -> (S, S::Len) {
+		let this = ManuallyDrop::new(self);
+
+		// SAFETY: The list is not dropped, so the storage is moved out once.
+		(unsafe { core::ptr::read(&this.inner) }, this.len)
+	}
+
+	/// Returns a pointer to the first slot of the storage, which unlike the
+	/// pointer of the dereferenced slice also covers the spare capacity.
+	#[inline]
+	pub(super) fn storage_ptr(&self) -> *const S::Item {
+		self.inner.as_ptr()
+	}
+
+	/// Returns a mutable pointer to the first element of the list.
+	#[inline]
+	#[must_use]
+	pub fn as_mut_ptr(&mut self) -> *mut S::Item {
+		self.inner.as_mut_ptr()
+	}
+
 	/// Extracts a mutable slice of the entire list.
 	#[inline]
 	#[must_use]
-	pub fn as_mut_slice(&mut self) -> &mut [T] {
-		// SAFETY: `self.len` is always a valid length for the array.
+	pub fn as_mut_slice(&mut self) -> &mut [S::Item] {
+		// SAFETY: `self.len` is always a valid length for the storage.
 		unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
 	}
 
+	/// Returns the remaining spare capacity of the list as a slice of
+	/// uninitialized elements.
+	#[inline]
+	#[must_use]
+	pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<S::Item>] {
+		let len = self.len();
+		let spare = self.capacity() - len;
+
+		// SAFETY: The slots after `len` are within the storage.
+		unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr().add(len).cast(), spare) }
+	}
+
+	/// Forces the length of the list to `len` without dropping or initializing
+	/// any elements.
+	///
+	/// # Safety
+	///
+	/// `len` must be at most the capacity, and the elements in `0..len` must be
+	/// initialized.
+	#[inline]
+	pub unsafe fn set_len(&mut self, len: usize) {
+		debug_assert!(len <= self.capacity(), "`len` must be at most the capacity");
+
+		self.len = unsafe { S::Len::new_unchecked(len) };
+	}
+
 	/// Clears the list, removing all values.
 	#[inline]
 	pub fn clear(&mut self) {
-		let slice: *mut [T] = core::ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len());
+		let slice: *mut [S::Item] =
+			core::ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len());
 
-		self.len = 0;
+		self.len = S::Len::ZERO;
 
 		// SAFETY: `slice` is a valid slice of length `self.len`.
 		unsafe { slice.drop_in_place() };
 	}
 
+	/// Shortens the list, keeping the first `len` elements and dropping the
+	/// rest. Does nothing if `len` is not less than the current length.
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		let old_len = self.len();
+
+		if len < old_len {
+			// SAFETY: `len` is less than `old_len`, so the tail is within the list.
+			let tail = unsafe { self.as_mut_ptr().add(len) };
+			let slice: *mut [S::Item] = core::ptr::slice_from_raw_parts_mut(tail, old_len - len);
+
+			self.len = unsafe { S::Len::new_unchecked(len) };
+
+			// SAFETY: `slice` is a valid slice of the removed elements.
+			unsafe { slice.drop_in_place() };
+		}
+	}
+
 	/// Inserts an element at position `index` within the list, shifting all
 	/// elements after it to the right.
 	///
@@ -104,17 +317,17 @@ impl<T, const N: usize> Fixed<T, N> {
 	/// Returns `Err` with the inserted value if the list is at capacity or the
 	/// index is out of bounds.
 	#[inline]
-	pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), T> {
+	pub fn try_insert(&mut self, index: usize, value: S::Item) -> Result<(), S::Item> {
 		let len = self.len();
 
 		if len < self.capacity() && index <= len {
-			// SAFETY: `index` is less than or equal to `len`, and `len + 1` is a valid length for the array.
-			let start = unsafe { self.inner.as_mut_ptr().add(index) };
+			// SAFETY: `index` is less than or equal to `len`, and `len + 1` is a valid length for the storage.
+			let start = unsafe { self.as_mut_ptr().add(index) };
 
 			unsafe { start.add(1).copy_from(start, len - index) };
-			unsafe { start.cast::<T>().write(value) };
+			unsafe { start.write(value) };
 
-			self.len += 1;
+			self.len = unsafe { S::Len::new_unchecked(len + 1) };
 
 			Ok(())
 		} else {
@@ -128,7 +341,7 @@ impl<T, const N: usize> Fixed<T, N> {
 	///
 	/// Returns `Err` with the pushed value if the list is at capacity.
 	#[inline]
-	pub fn try_push(&mut self, value: T) -> Result<(), T> {
+	pub fn try_push(&mut self, value: S::Item) -> Result<(), S::Item> {
 		let len = self.len();
 
 		self.try_insert(len, value)
@@ -138,17 +351,17 @@ impl<T, const N: usize> Fixed<T, N> {
 	/// shifting all elements after it to the left, or returns [`None`] if the
 	/// index is out of bounds.
 	#[inline]
-	pub fn try_remove(&mut self, index: usize) -> Option<T> {
+	pub fn try_remove(&mut self, index: usize) -> Option<S::Item> {
 		let len = self.len();
 
 		if index < len {
-			// SAFETY: `index` is less than `len`, and `len` is a valid length for the array.
-			let start = unsafe { self.inner.as_mut_ptr().add(index) };
-			let value = unsafe { start.cast::<T>().read() };
+			// SAFETY: `index` is less than `len`, and `len` is a valid length for the storage.
+			let start = unsafe { self.as_mut_ptr().add(index) };
+			let value = unsafe { start.read() };
 
 			unsafe { start.add(1).copy_to(start, len - index - 1) };
 
-			self.len -= 1;
+			self.len = unsafe { S::Len::new_unchecked(len - 1) };
 
 			Some(value)
 		} else {
@@ -159,7 +372,7 @@ impl<T, const N: usize> Fixed<T, N> {
 	/// Removes the last element from a list and returns it, or [`None`] if it
 	/// is empty.
 	#[inline]
-	pub fn try_pop(&mut self) -> Option<T> {
+	pub fn try_pop(&mut self) -> Option<S::Item> {
 		let last = self.len().checked_sub(1)?;
 
 		self.try_remove(last)
@@ -169,11 +382,11 @@ impl<T, const N: usize> Fixed<T, N> {
 	///
 	/// The removed element is replaced by the last element of the list.
 	#[inline]
-	pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+	pub fn try_swap_remove(&mut self, index: usize) -> Option<S::Item> {
 		let last = self.len().checked_sub(1)?;
 
 		if index <= last {
-			let start = self.inner.as_mut_ptr();
+			let start = self.as_mut_ptr();
 
 			unsafe { core::ptr::swap(start.add(index), start.add(last)) };
 
@@ -183,72 +396,110 @@ impl<T, const N: usize> Fixed<T, N> {
 		}
 	}
 
-	/// Converts the list into a `Vec<T>`, consuming the list.
+	/// Removes the elements in `range` from the list, returning them as an
+	/// iterator. Elements left in the iterator when it is dropped are dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is decreasing or out of bounds.
 	#[inline]
-	pub fn to_vec_reserve(self, additional: usize) -> Vec<T> {
-		let mut vec = Vec::with_capacity(self.len() + additional);
-
-		vec.extend(self);
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, S> {
+		let (start, end) = drain::range(range, self.len());
 
-		vec
+		// SAFETY: `start..end` is within the list, which stays borrowed by the
+		// iterator.
+		unsafe { Drain::new(NonNull::from(self), start, end) }
 	}
 
-	/// Creates a `Fixed<T, N>` directly from an array of `MaybeUninit<T>` and a length.
+	/// Converts the list into a `Vec<T>` with room for at least `additional`
+	/// more elements, consuming the list.
 	///
-	/// # Safety
+	/// # Panics
 	///
-	/// `len` must be a valid length for the initialized part of the array.
+	/// Panics if the new capacity overflows `usize`.
+	#[cfg(feature = "alloc")]
 	#[inline]
-	pub const unsafe fn from_raw_parts(inner: [MaybeUninit<T>; N], len: u8) -> Self {
-		Self { len, inner }
-	}
+	pub fn to_vec_reserve(mut self, additional: usize) -> Vec<S::Item> {
+		let len = self.len();
+		let capacity = len.checked_add(additional).expect("capacity overflow");
+		let mut vec = Vec::<S::Item>::with_capacity(capacity);
 
-	/// Decomposes a `Fixed<T, N>` into its raw components.
-	#[inline]
-	pub fn into_raw_parts(mut self) -> ([MaybeUninit<T>; N], u8) {
-		let inner = core::mem::replace(&mut self.inner, unsafe {
-			MaybeUninit::uninit().assume_init()
-		});
-		let len = core::mem::take(&mut self.len);
+		// SAFETY: The elements are moved to the vector, which has room for them,
+		// and are no longer owned by the list.
+		unsafe {
+			vec.as_mut_ptr()
+				.copy_from_nonoverlapping(self.as_ptr(), len);
+
+			self.set_len(0);
+			vec.set_len(len);
+		}
 
-		(inner, len)
+		vec
 	}
 
 	/// Retains only the elements specified by the predicate.
-	pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+	pub fn retain(&mut self, mut f: impl FnMut(&S::Item) -> bool) {
 		self.retain_mut(|item| f(item));
 	}
 
 	/// Retains only the elements specified by the predicate, passing a mutable reference to it.
-	pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
-		struct Guard<'a, T, const N: usize> {
-			iter: IntoIter<T, N>,
-			list: &'a mut Fixed<T, N>,
+	pub fn retain_mut(&mut self, mut f: impl FnMut(&mut S::Item) -> bool) {
+		// Elements before `read` have been visited, and `deleted` of them were
+		// dropped, leaving a hole of that size just before `read`.
+		struct Guard<'a, S: Storage> {
+			list: &'a mut FixedIn<S>,
+			read: usize,
+			deleted: usize,
+			len: usize,
 		}
 
-		impl<T, const N: usize> Drop for Guard<'_, T, N> {
+		impl<S: Storage> Drop for Guard<'_, S> {
 			fn drop(&mut self) {
-				for item in self.iter.by_ref() {
-					// SAFETY: Both arrays have the same capacity.
-					unsafe { self.list.try_push(item).unwrap_unchecked() };
+				if self.deleted != 0 {
+					let start = self.list.as_mut_ptr();
+
+					// SAFETY: The unvisited elements are moved to close the hole.
+					unsafe {
+						let read = start.add(self.read);
+
+						read.copy_to(read.sub(self.deleted), self.len - self.read);
+					}
 				}
+
+				self.list.len = unsafe { S::Len::new_unchecked(self.len - self.deleted) };
 			}
 		}
 
+		let len = self.len();
+
+		// The length is cleared so the elements are not dropped twice if the
+		// predicate or a destructor panics.
+		self.len = S::Len::ZERO;
+
 		let mut guard = Guard {
-			iter: core::mem::take(self).into_iter(),
 			list: self,
+			read: 0,
+			deleted: 0,
+			len,
 		};
 
-		for item in guard.iter.by_ref() {
-			let list = &mut *guard.list;
-
-			// SAFETY: Both arrays have the same capacity.
+		while guard.read < guard.len {
+			// SAFETY: `read` is less than the original length, and the hole is
+			// always before it.
 			unsafe {
-				list.try_push(item).unwrap_unchecked();
+				let current = guard.list.as_mut_ptr().add(guard.read);
+
+				if f(&mut *current) {
+					if guard.deleted != 0 {
+						current.copy_to_nonoverlapping(current.sub(guard.deleted), 1);
+					}
+
+					guard.read += 1;
+				} else {
+					guard.read += 1;
+					guard.deleted += 1;
 
-				if !f(list.last_mut().unwrap_unchecked()) {
-					list.try_pop().unwrap_unchecked();
+					current.drop_in_place();
 				}
 			}
 		}
@@ -257,22 +508,22 @@ impl<T, const N: usize> Fixed<T, N> {
 	}
 }
 
-impl<T, const N: usize> IntoIterator for Fixed<T, N> {
-	type Item = T;
-	type IntoIter = IntoIter<T, N>;
+impl<S: Storage> IntoIterator for FixedIn<S> {
+	type Item = S::Item;
+	type IntoIter = IntoIterIn<S>;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
-		let (inner, len) = self.into_raw_parts();
+		let (inner, len) = self.into_storage_parts();
 
-		// SAFETY: `len` is always a valid length for the array.
-		unsafe { Self::IntoIter::new_unchecked(inner, 0, len) }
+		// SAFETY: `len` is always a valid length for the storage.
+		unsafe { IntoIterIn::from_storage_unchecked(inner, S::Len::ZERO, len) }
 	}
 }
 
-impl<'a, T, const N: usize> IntoIterator for &'a Fixed<T, N> {
-	type Item = &'a T;
-	type IntoIter = core::slice::Iter<'a, T>;
+impl<'a, S: Storage> IntoIterator for &'a FixedIn<S> {
+	type Item = &'a S::Item;
+	type IntoIter = core::slice::Iter<'a, S::Item>;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
@@ -280,9 +531,9 @@ impl<'a, T, const N: usize> IntoIterator for &'a Fixed<T, N> {
 	}
 }
 
-impl<'a, T, const N: usize> IntoIterator for &'a mut Fixed<T, N> {
-	type Item = &'a mut T;
-	type IntoIter = core::slice::IterMut<'a, T>;
+impl<'a, S: Storage> IntoIterator for &'a mut FixedIn<S> {
+	type Item = &'a mut S::Item;
+	type IntoIter = core::slice::IterMut<'a, S::Item>;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
@@ -290,25 +541,76 @@ impl<'a, T, const N: usize> IntoIterator for &'a mut Fixed<T, N> {
 	}
 }
 
-impl<T, const N: usize> Default for Fixed<T, N> {
+/// The length and capacity of lists over any storage. The storage kinds of the
+/// crate also have inherent versions, which are `const` for inline arrays.
+impl<S: Storage> List<S::Item> for FixedIn<S> {
+	#[inline]
+	fn len(&self) -> usize {
+		let len = self.len.get();
+
+		if len > self.inner.capacity() {
+			// SAFETY: `len` is always a valid length for the storage.
+			unsafe { core::hint::unreachable_unchecked() };
+		}
+
+		len
+	}
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		self.inner.capacity()
+	}
+
+	#[inline]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	#[inline]
+	fn clear(&mut self) {
+		self.clear();
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: S::Item) -> Result<(), S::Item> {
+		self.try_push(value)
+	}
+
+	#[inline]
+	fn try_insert(&mut self, index: usize, value: S::Item) -> Result<(), S::Item> {
+		self.try_insert(index, value)
+	}
+
+	#[inline]
+	fn try_remove(&mut self, index: usize) -> Option<S::Item> {
+		self.try_remove(index)
+	}
+
+	#[inline]
+	fn pop(&mut self) -> Option<S::Item> {
+		self.try_pop()
+	}
+}
+
+impl<S: InlineStorage> Default for FixedIn<S> {
 	#[inline]
 	fn default() -> Self {
 		Self::new()
 	}
 }
 
-impl<T, const N: usize> Extend<T> for Fixed<T, N> {
+impl<S: Storage> Extend<S::Item> for FixedIn<S> {
 	#[inline]
-	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+	fn extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) {
 		iter.into_iter()
 			.try_for_each(|value| self.try_push(value))
 			.unwrap_or_else(|_| panic!("`Fixed` capacity exceeded"));
 	}
 }
 
-impl<T, const N: usize> FromIterator<T> for Fixed<T, N> {
+impl<S: InlineStorage> FromIterator<S::Item> for FixedIn<S> {
 	#[inline]
-	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+	fn from_iter<I: IntoIterator<Item = S::Item>>(iter: I) -> Self {
 		let mut result = Self::new();
 
 		result.extend(iter);
@@ -317,37 +619,75 @@ impl<T, const N: usize> FromIterator<T> for Fixed<T, N> {
 	}
 }
 
-impl<T: Clone, const N: usize> Clone for Fixed<T, N> {
+impl<S: InlineStorage> Clone for FixedIn<S>
+where
+	S::Item: Clone,
+{
 	#[inline]
 	fn clone(&self) -> Self {
 		self.iter().cloned().collect()
 	}
+
+	#[inline]
+	fn clone_from(&mut self, source: &Self) {
+		self.truncate(source.len());
+
+		let (init, tail) = source.split_at(self.len());
+
+		self.clone_from_slice(init);
+
+		for value in tail {
+			// The source has the same capacity, so the value always fits.
+			let _ = self.try_push(value.clone());
+		}
+	}
 }
 
-impl<T, const N: usize> Drop for Fixed<T, N> {
+impl<S: Storage> Drop for FixedIn<S> {
 	#[inline]
 	fn drop(&mut self) {
 		self.clear();
+
+		#[cfg(feature = "zeroize")]
+		zeroize::Zeroize::zeroize(self.spare_capacity_mut());
 	}
 }
 
-impl<T, const N: usize> core::ops::Deref for Fixed<T, N> {
-	type Target = [T];
+impl<S: Storage> core::ops::Deref for FixedIn<S> {
+	type Target = [S::Item];
 
 	#[inline]
 	fn deref(&self) -> &Self::Target {
-		self.as_slice()
+		// SAFETY: `self.len` is always a valid length for the storage.
+		unsafe { core::slice::from_raw_parts(self.storage_ptr(), List::len(self)) }
 	}
 }
 
-impl<T, const N: usize> core::ops::DerefMut for Fixed<T, N> {
+impl<S: Storage> core::ops::DerefMut for FixedIn<S> {
 	#[inline]
 	fn deref_mut(&mut self) -> &mut Self::Target {
 		self.as_mut_slice()
 	}
 }
 
-impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Fixed<T, N> {
+impl<S: Storage> AsRef<[S::Item]> for FixedIn<S> {
+	#[inline]
+	fn as_ref(&self) -> &[S::Item] {
+		self.as_slice()
+	}
+}
+
+impl<S: Storage> AsMut<[S::Item]> for FixedIn<S> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [S::Item] {
+		self.as_mut_slice()
+	}
+}
+
+impl<S: Storage> core::fmt::Debug for FixedIn<S>
+where
+	S::Item: core::fmt::Debug,
+{
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		self.as_slice().fmt(f)
 	}
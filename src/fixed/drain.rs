@@ -0,0 +1,190 @@
+use core::{
+	fmt,
+	iter::FusedIterator,
+	marker::PhantomData,
+	ops::{Bound, RangeBounds},
+	ptr::NonNull,
+};
+
+use super::{collection::FixedIn, storage::Storage};
+
+/// A draining iterator over a range of elements of a [`FixedIn`].
+///
+/// The list is shortened to the start of the range while the iterator is
+/// alive, so leaking it leaks the drained and trailing elements rather than
+/// exposing moved-from values.
+pub struct Drain<'a, S: Storage> {
+	iter: core::slice::Iter<'a, S::Item>,
+	list: NonNull<FixedIn<S>>,
+	tail_start: usize,
+	tail_len: usize,
+	marker: PhantomData<&'a mut FixedIn<S>>,
+}
+
+impl<S: Storage> Drain<'_, S> {
+	/// Creates a draining iterator over `start..end` of `list`.
+	///
+	/// # Safety
+	///
+	/// `list` must stay mutably borrowed for the lifetime of the iterator, and
+	/// `start..end` must be within its elements.
+	#[inline]
+	pub(super) unsafe fn new(mut list: NonNull<FixedIn<S>>, start: usize, end: usize) -> Self {
+		// SAFETY: The caller lends the list to the iterator.
+		let source = unsafe { list.as_mut() };
+		let tail_len = source.len() - end;
+
+		unsafe {
+			source.set_len(start);
+		}
+
+		Self {
+			// SAFETY: The drained range is initialized and no longer part of
+			// the list.
+			iter: unsafe {
+				core::slice::from_raw_parts(source.storage_ptr().add(start), end - start)
+			}
+			.iter(),
+			list,
+			tail_start: end,
+			tail_len,
+			marker: PhantomData,
+		}
+	}
+
+	/// Returns the remaining elements of the iterator as a slice.
+	#[inline]
+	#[must_use]
+	pub fn as_slice(&self) -> &[S::Item] {
+		self.iter.as_slice()
+	}
+}
+
+impl<S: Storage> Iterator for Drain<'_, S> {
+	type Item = S::Item;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// SAFETY: Each element is read at most once before it leaves the iterator.
+		self.iter
+			.next()
+			.map(|value| unsafe { core::ptr::read(value) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl<S: Storage> DoubleEndedIterator for Drain<'_, S> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		// SAFETY: Each element is read at most once before it leaves the iterator.
+		self.iter
+			.next_back()
+			.map(|value| unsafe { core::ptr::read(value) })
+	}
+}
+
+impl<S: Storage> ExactSizeIterator for Drain<'_, S> {}
+
+impl<S: Storage> FusedIterator for Drain<'_, S> {}
+
+impl<S: Storage> fmt::Debug for Drain<'_, S>
+where
+	S::Item: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Drain").field(&self.as_slice()).finish()
+	}
+}
+
+impl<S: Storage> Drop for Drain<'_, S> {
+	fn drop(&mut self) {
+		// Moves the tail back even if dropping a remaining element panics.
+		struct Guard<'a, 'b, S: Storage>(&'a mut Drain<'b, S>);
+
+		impl<S: Storage> Drop for Guard<'_, '_, S> {
+			fn drop(&mut self) {
+				let drain = &mut *self.0;
+				// SAFETY: The list is still lent to the iterator.
+				let list = unsafe { drain.list.as_mut() };
+				let start = list.len();
+
+				// SAFETY: The tail is initialized, and the hole before it holds
+				// no live elements.
+				unsafe {
+					let base = list.as_mut_ptr();
+
+					base.add(drain.tail_start)
+						.copy_to(base.add(start), drain.tail_len);
+
+					list.set_len(start + drain.tail_len);
+				}
+			}
+		}
+
+		let remaining = core::mem::take(&mut self.iter).as_slice();
+		let (offset, count) = match core::mem::size_of::<S::Item>() {
+			0 => (0, remaining.len()),
+			// SAFETY: The remaining elements are within the list.
+			_ => (
+				unsafe {
+					remaining
+						.as_ptr()
+						.offset_from(self.list.as_ref().storage_ptr())
+				} as usize,
+				remaining.len(),
+			),
+		};
+		let guard = Guard(self);
+
+		// SAFETY: The remaining elements were never yielded. The pointer is
+		// rebuilt from the list rather than the shared iterator.
+		unsafe {
+			let list = guard.0.list.as_mut();
+			let remaining =
+				core::ptr::slice_from_raw_parts_mut(list.as_mut_ptr().add(offset), count);
+
+			remaining.drop_in_place();
+		}
+
+		drop(guard);
+	}
+}
+
+/// Resolves `range` against a list of length `len` into `start..end`.
+///
+/// # Panics
+///
+/// Panics if the range is decreasing or out of bounds.
+#[track_caller]
+pub(super) fn range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+	let start = match range.start_bound() {
+		Bound::Included(&start) => start,
+		Bound::Excluded(&start) => start
+			.checked_add(1)
+			.unwrap_or_else(|| panic!("attempted to index slice from after maximum usize")),
+		Bound::Unbounded => 0,
+	};
+
+	let end = match range.end_bound() {
+		Bound::Included(&end) => end
+			.checked_add(1)
+			.unwrap_or_else(|| panic!("attempted to index slice up to maximum usize")),
+		Bound::Excluded(&end) => end,
+		Bound::Unbounded => len,
+	};
+
+	assert!(
+		start <= end,
+		"slice index starts at {start} but ends at {end}"
+	);
+	assert!(
+		end <= len,
+		"range end index {end} out of range for slice of length {len}"
+	);
+
+	(start, end)
+}
@@ -0,0 +1,172 @@
+use core::{fmt, mem::MaybeUninit};
+
+use super::{
+	collection::FixedIn,
+	slice::FixedSlice,
+	storage::{InlineStorage, Storage},
+};
+
+/// A C header declaring the layouts of [`FixedFfi`] and [`RawParts`].
+///
+/// Write it out from a build script to use the types from C.
+pub const C_HEADER: &str = include_str!("../../include/list.h");
+
+/// An inline array which stores its length as a `usize`, so that
+/// [`FixedFfi`] has a layout C code can rely on.
+#[repr(transparent)]
+#[cfg_attr(
+	feature = "zerocopy",
+	derive(zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+pub struct CArray<T, const N: usize>([MaybeUninit<T>; N]);
+
+unsafe impl<T, const N: usize> Storage for CArray<T, N> {
+	type Item = T;
+	type Len = usize;
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		N
+	}
+
+	#[inline]
+	fn as_ptr(&self) -> *const T {
+		self.0.as_ptr().cast()
+	}
+
+	#[inline]
+	fn as_mut_ptr(&mut self) -> *mut T {
+		self.0.as_mut_ptr().cast()
+	}
+}
+
+unsafe impl<T, const N: usize> InlineStorage for CArray<T, N> {
+	const CAPACITY: usize = N;
+	const UNINIT: Self = Self([const { MaybeUninit::uninit() }; N]);
+}
+
+/// A contiguous fixed-size list of elements of type `T` with a stable layout,
+/// for passing across an FFI boundary.
+///
+/// The list is laid out like the C struct below, which [`C_HEADER`] declares
+/// through its `LIST_FIXED(T, N)` macro:
+///
+/// ```c
+/// struct {
+///     size_t len;
+///     T data[N];
+/// };
+/// ```
+///
+/// The first `len` elements of `data` are initialized, and `len` is never
+/// greater than `N`. C code that changes `len` must uphold both.
+pub type FixedFfi<T, const N: usize> = FixedIn<CArray<T, N>>;
+
+/// The pointer, length and capacity of a list buffer, laid out like
+/// `LIST_RAW_PARTS(T)` in [`C_HEADER`].
+///
+/// The first `len` elements at `ptr` are initialized, and `len` is never
+/// greater than `cap`.
+#[repr(C)]
+pub struct RawParts<T> {
+	/// A pointer to the first slot of the buffer.
+	pub ptr: *mut T,
+	/// The number of initialized elements.
+	pub len: usize,
+	/// The number of slots in the buffer.
+	pub cap: usize,
+}
+
+impl<T> Clone for RawParts<T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for RawParts<T> {}
+
+impl<T> fmt::Debug for RawParts<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("RawParts")
+			.field("ptr", &self.ptr)
+			.field("len", &self.len)
+			.field("cap", &self.cap)
+			.finish()
+	}
+}
+
+impl<T, const N: usize> FixedFfi<T, N> {
+	/// Returns the raw parts of the buffer, so that C code can read or fill it
+	/// in place.
+	///
+	/// The pointer is valid until the list is moved or dropped. If C code adds
+	/// or removes elements, write the new length back with
+	/// [`set_len`](FixedIn::set_len).
+	#[inline]
+	#[must_use]
+	pub fn as_raw_parts(&mut self) -> RawParts<T> {
+		RawParts {
+			ptr: self.as_mut_ptr(),
+			len: self.len(),
+			cap: N,
+		}
+	}
+}
+
+impl<'a, T> FixedSlice<'a, T> {
+	/// Creates a list over a buffer described by raw parts, such as one passed
+	/// in by C code, taking ownership of its elements.
+	///
+	/// A null pointer is accepted for an empty buffer.
+	///
+	/// # Panics
+	///
+	/// Panics if the length is greater than the capacity, or if the pointer is
+	/// misaligned or is null for a non-empty buffer.
+	///
+	/// # Safety
+	///
+	/// `ptr` must be valid for reads and writes of `cap` elements for `'a`, the
+	/// first `len` of which are initialized, and must not be accessed through
+	/// any other pointer while the list is alive.
+	#[inline]
+	#[must_use]
+	pub unsafe fn from_raw_parts(parts: RawParts<T>) -> Self {
+		let RawParts { ptr, len, cap } = parts;
+
+		assert!(len <= cap, "`len` exceeds `cap`");
+
+		let inner: &'a mut [MaybeUninit<T>] = if ptr.is_null() && cap == 0 {
+			&mut []
+		} else {
+			assert!(!ptr.is_null(), "`ptr` is null");
+			assert!(ptr.is_aligned(), "`ptr` is misaligned");
+
+			// SAFETY: The caller guarantees `ptr` is valid for `cap` elements.
+			unsafe { core::slice::from_raw_parts_mut(ptr.cast(), cap) }
+		};
+
+		let mut list = Self::new(inner);
+
+		// SAFETY: The caller guarantees the first `len` elements are
+		// initialized, and `len` is at most the capacity.
+		unsafe { list.set_len(len) };
+
+		list
+	}
+
+	/// Decomposes the list into raw parts without dropping its elements, which
+	/// are left in the buffer for its owner to drop.
+	#[inline]
+	#[must_use]
+	pub fn into_raw_parts(self) -> RawParts<T> {
+		let (inner, len) = self.into_storage_parts();
+
+		RawParts {
+			ptr: inner.as_mut_ptr().cast(),
+			len,
+			cap: inner.len(),
+		}
+	}
+}
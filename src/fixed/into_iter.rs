@@ -1,19 +1,28 @@
-use core::{iter::FusedIterator, mem::MaybeUninit};
+use core::{iter::FusedIterator, mem::MaybeUninit, num::NonZeroUsize};
+
+use super::{
+	collection::FixedIn,
+	len::{Len, Length},
+	storage::{InlineStorage, Storage},
+};
+
+/// A by-value iterator over the elements of a [`FixedIn`](super::FixedIn).
+pub struct IntoIterIn<S: Storage> {
+	start: S::Len,
+	end: S::Len,
+	inner: S,
+}
 
 /// A by-value short array iterator.
 /// Needed until [`std::array::IntoIter::new_unchecked`] is stabilized.
-pub struct IntoIter<T, const N: usize> {
-	start: u8,
-	end: u8,
-	inner: [MaybeUninit<T>; N],
-}
+pub type IntoIter<T, const N: usize> = IntoIterIn<[MaybeUninit<T>; N]>;
 
 impl<T, const N: usize> IntoIter<T, N> {
 	/// Creates an iterator over the elements in a partially-initialized buffer.
 	///
 	/// # Panics
 	///
-	/// Panics if the capacity of the list does not fit within a `u8`.
+	/// Panics if the capacity of the list is greater than 254.
 	///
 	/// # Safety
 	///
@@ -24,86 +33,284 @@ impl<T, const N: usize> IntoIter<T, N> {
 		debug_assert!(start <= end, "`start` must be less than or equal to `end`");
 		debug_assert!(end as usize <= N, "`end` must be less than or equal to `N`");
 
-		assert!(N <= u8::MAX as usize, "`Fixed` capacity exceeds `u8`");
+		assert!(N <= Len::MAX, "`Fixed` capacity exceeds 254");
 
-		Self { start, end, inner }
+		Self {
+			start: unsafe { Len::new_unchecked(start as usize) },
+			end: unsafe { Len::new_unchecked(end as usize) },
+			inner,
+		}
 	}
+}
 
+impl<S: InlineStorage> IntoIterIn<S> {
 	/// Creates an iterator which returns no elements.
 	///
 	/// # Panics
 	///
-	/// Panics if the capacity of the list does not fit within a `u8`.
+	/// Panics if the capacity of the list is greater than 254.
 	#[inline]
 	#[must_use]
 	pub const fn empty() -> Self {
-		let inner = unsafe { MaybeUninit::uninit().assume_init() };
+		assert!(S::CAPACITY <= S::Len::MAX, "`Fixed` capacity exceeds 254");
 
-		unsafe { Self::new_unchecked(inner, 0, 0) }
+		Self {
+			start: S::Len::ZERO,
+			end: S::Len::ZERO,
+			inner: S::UNINIT,
+		}
 	}
 }
 
-impl<T, const N: usize> Iterator for IntoIter<T, N> {
-	type Item = T;
+impl<S: Storage> IntoIterIn<S> {
+	/// Creates an iterator over the elements of `inner` in `start..end`.
+	///
+	/// # Safety
+	///
+	/// The elements in the storage must be initialized in the range
+	/// `start..end`.
+	#[inline]
+	pub(super) unsafe fn from_storage_unchecked(inner: S, start: S::Len, end: S::Len) -> Self {
+		Self { start, end, inner }
+	}
+
+	/// Returns the number of remaining elements.
+	#[inline]
+	fn remaining(&self) -> usize {
+		let len = self.end.get() - self.start.get();
+
+		if len > self.inner.capacity() {
+			// SAFETY: `len` is always a valid length for the storage.
+			unsafe { core::hint::unreachable_unchecked() };
+		}
+
+		len
+	}
+
+	/// Returns the remaining elements of the iterator as a slice.
+	#[inline]
+	#[must_use]
+	pub fn as_slice(&self) -> &[S::Item] {
+		let start = self.start.get();
+
+		// SAFETY: The elements in `start..end` are always initialized.
+		unsafe { core::slice::from_raw_parts(self.inner.as_ptr().add(start), self.remaining()) }
+	}
+
+	/// Returns the remaining elements of the iterator as a mutable slice.
+	#[inline]
+	#[must_use]
+	pub fn as_mut_slice(&mut self) -> &mut [S::Item] {
+		let start = self.start.get();
+		let len = self.remaining();
+
+		// SAFETY: The elements in `start..end` are always initialized.
+		unsafe { core::slice::from_raw_parts_mut(self.inner.as_mut_ptr().add(start), len) }
+	}
+
+	/// Advances the iterator from the front by `n` elements, dropping them in
+	/// bulk.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the number of elements that could not be skipped if
+	/// the iterator has fewer than `n` elements.
+	#[inline]
+	pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+		let step = n.min(self.remaining());
+		let start = self.start.get();
+
+		// SAFETY: `step` is at most the remaining length, so the skipped elements
+		// are initialized and are no longer yielded once `start` moves past them.
+		unsafe {
+			let skipped = self.inner.as_mut_ptr().add(start);
+			let slice: *mut [S::Item] = core::ptr::slice_from_raw_parts_mut(skipped, step);
+
+			self.start = S::Len::new_unchecked(start + step);
+
+			slice.drop_in_place();
+		}
+
+		NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+	}
+
+	/// Advances the iterator from the back by `n` elements, dropping them in
+	/// bulk.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the number of elements that could not be skipped if
+	/// the iterator has fewer than `n` elements.
+	#[inline]
+	pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+		let step = n.min(self.remaining());
+		let end = self.end.get() - step;
+
+		// SAFETY: `step` is at most the remaining length, so the skipped elements
+		// are initialized and are no longer yielded once `end` moves before them.
+		unsafe {
+			self.end = S::Len::new_unchecked(end);
+
+			let skipped = self.inner.as_mut_ptr().add(end);
+			let slice: *mut [S::Item] = core::ptr::slice_from_raw_parts_mut(skipped, step);
+
+			slice.drop_in_place();
+		}
+
+		NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+	}
+}
+
+impl<S: Storage> Iterator for IntoIterIn<S> {
+	type Item = S::Item;
 
 	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.start >= self.end {
+		let start = self.start.get();
+
+		if start >= self.end.get() {
 			None
 		} else {
-			let start = usize::from(self.start);
+			self.start = unsafe { S::Len::new_unchecked(start + 1) };
 
-			self.start += 1;
-
-			Some(unsafe { self.inner.get_unchecked(start).assume_init_read() })
+			Some(unsafe { self.inner.as_ptr().add(start).read() })
 		}
 	}
 
 	#[inline]
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let len = usize::from(self.end - self.start);
-
-		if len > N {
-			// SAFETY: `len` is always a valid length for the array.
-			unsafe { core::hint::unreachable_unchecked() };
-		}
+		let len = self.remaining();
 
 		(len, Some(len))
 	}
 
 	#[inline]
 	fn count(self) -> usize {
-		self.len()
+		self.remaining()
 	}
 
 	#[inline]
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.advance_by(n).ok()?;
+		self.next()
+	}
+
+	#[inline]
+	fn fold<B, F>(mut self, init: B, mut f: F) -> B
+	where
+		F: FnMut(B, Self::Item) -> B,
+	{
+		let mut accum = init;
+
+		while self.start.get() < self.end.get() {
+			let start = self.start.get();
+
+			self.start = unsafe { S::Len::new_unchecked(start + 1) };
+
+			accum = f(accum, unsafe { self.inner.as_ptr().add(start).read() });
+		}
+
+		accum
+	}
 }
 
-impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+impl<S: Storage> DoubleEndedIterator for IntoIterIn<S> {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.start >= self.end {
+		let end = self.end.get();
+
+		if self.start.get() >= end {
 			None
 		} else {
-			self.end -= 1;
+			self.end = unsafe { S::Len::new_unchecked(end - 1) };
 
-			let end = usize::from(self.end);
+			Some(unsafe { self.inner.as_ptr().add(end - 1).read() })
+		}
+	}
 
-			Some(unsafe { self.inner.get_unchecked(end).assume_init_read() })
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.advance_back_by(n).ok()?;
+		self.next_back()
+	}
+
+	#[inline]
+	fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+	where
+		F: FnMut(B, Self::Item) -> B,
+	{
+		let mut accum = init;
+
+		while self.start.get() < self.end.get() {
+			let end = self.end.get() - 1;
+
+			self.end = unsafe { S::Len::new_unchecked(end) };
+
+			accum = f(accum, unsafe { self.inner.as_ptr().add(end).read() });
 		}
+
+		accum
 	}
 }
 
-impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+impl<S: Storage> ExactSizeIterator for IntoIterIn<S> {}
 
-impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+impl<S: Storage> FusedIterator for IntoIterIn<S> {}
 
-impl<T, const N: usize> Drop for IntoIter<T, N> {
+impl<S: InlineStorage> Default for IntoIterIn<S> {
+	#[inline]
+	fn default() -> Self {
+		Self::empty()
+	}
+}
+
+impl<S: InlineStorage> Clone for IntoIterIn<S>
+where
+	S::Item: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		self.as_slice()
+			.iter()
+			.cloned()
+			.collect::<FixedIn<S>>()
+			.into_iter()
+	}
+}
+
+impl<S: Storage> core::fmt::Debug for IntoIterIn<S>
+where
+	S::Item: core::fmt::Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+	}
+}
+
+impl<S: Storage> Drop for IntoIterIn<S> {
 	#[inline]
 	fn drop(&mut self) {
-		self.for_each(drop);
+		let slice: *mut [S::Item] = self.as_mut_slice();
+
+		self.start = self.end;
+
+		// SAFETY: `slice` holds the remaining elements, which are no longer yielded.
+		unsafe { slice.drop_in_place() };
+
+		// SAFETY: Every slot has been moved out or dropped, so the whole buffer
+		// is wiped as uninitialized memory, including the elements already
+		// yielded.
+		#[cfg(feature = "zeroize")]
+		zeroize::Zeroize::zeroize(unsafe {
+			core::slice::from_raw_parts_mut(
+				self.inner.as_mut_ptr().cast::<MaybeUninit<S::Item>>(),
+				self.inner.capacity(),
+			)
+		});
 	}
 }
@@ -0,0 +1,93 @@
+use core::num::NonZeroU8;
+
+/// The type a list stores its length as, chosen by its [`Storage`].
+///
+/// This trait is sealed: it is implemented for [`Len`] and `usize` only.
+///
+/// [`Storage`]: super::Storage
+pub trait Length: Copy + sealed::Sealed {
+	/// The largest length that can be stored.
+	const MAX: usize;
+
+	/// A length of zero.
+	const ZERO: Self;
+
+	/// Creates a length without checking that it is at most [`Length::MAX`].
+	///
+	/// # Safety
+	///
+	/// `len` must be at most [`Length::MAX`].
+	unsafe fn new_unchecked(len: usize) -> Self;
+
+	/// Returns the length as a `usize`.
+	fn get(self) -> usize;
+}
+
+mod sealed {
+	pub trait Sealed {}
+
+	impl Sealed for super::Len {}
+	impl Sealed for usize {}
+}
+
+/// A length of at most 254, stored inverted in a `NonZeroU8`.
+///
+/// A length of 255 is never stored, which leaves that bit pattern free as a
+/// niche for `Option<Fixed<T, N>>` and the discriminant of enclosing enums.
+#[derive(Clone, Copy, Debug)]
+pub struct Len(NonZeroU8);
+
+impl Len {
+	/// The largest length that can be stored.
+	pub(crate) const MAX: usize = u8::MAX as usize - 1;
+
+	/// A length of zero.
+	pub(crate) const ZERO: Self = Self(NonZeroU8::MAX);
+
+	/// Creates a length without checking that it is at most [`Len::MAX`].
+	///
+	/// # Safety
+	///
+	/// `len` must be at most [`Len::MAX`].
+	#[inline]
+	pub(crate) const unsafe fn new_unchecked(len: usize) -> Self {
+		// SAFETY: `len` is at most 254, so its complement is never zero.
+		Self(unsafe { NonZeroU8::new_unchecked(!(len as u8)) })
+	}
+
+	/// Returns the length as a `usize`.
+	#[inline]
+	pub(crate) const fn get(self) -> usize {
+		(!self.0.get()) as usize
+	}
+}
+
+impl Length for Len {
+	const MAX: usize = Self::MAX;
+	const ZERO: Self = Self::ZERO;
+
+	#[inline]
+	unsafe fn new_unchecked(len: usize) -> Self {
+		unsafe { Self::new_unchecked(len) }
+	}
+
+	#[inline]
+	fn get(self) -> usize {
+		self.get()
+	}
+}
+
+impl Length for usize {
+	const MAX: usize = usize::MAX;
+	const ZERO: Self = 0;
+
+	#[inline]
+	unsafe fn new_unchecked(len: usize) -> Self {
+		len
+	}
+
+	#[inline]
+	fn get(self) -> usize {
+		self
+	}
+}
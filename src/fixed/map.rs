@@ -0,0 +1,53 @@
+use core::convert::Infallible;
+
+use super::collection::Fixed;
+
+impl<T, const N: usize> Fixed<T, N> {
+	/// Returns a list of the same capacity with `f` applied to each element,
+	/// in order.
+	///
+	/// If `f` panics, the elements mapped so far and those not yet mapped are
+	/// all dropped.
+	#[inline]
+	pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Fixed<U, N> {
+		match self.try_map(|value| Ok::<_, Infallible>(f(value))) {
+			Ok(list) => list,
+			Err(never) => match never {},
+		}
+	}
+
+	/// Returns a list of the same capacity with the fallible `f` applied to
+	/// each element, in order.
+	///
+	/// # Errors
+	///
+	/// Returns the first error returned by `f`, after dropping the elements
+	/// mapped so far and those not yet mapped.
+	#[inline]
+	pub fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<Fixed<U, N>, E> {
+		let mut result = Fixed::new();
+
+		for value in self {
+			// The result has the same capacity, so the value always fits.
+			let _ = result.try_push(f(value)?);
+		}
+
+		Ok(result)
+	}
+
+	/// Combines two lists of equal length into a list of pairs.
+	///
+	/// # Panics
+	///
+	/// Panics if the lists have different lengths.
+	#[inline]
+	pub fn zip<U>(self, other: Fixed<U, N>) -> Fixed<(T, U), N> {
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"`zip` called on lists of different lengths"
+		);
+
+		self.into_iter().zip(other).collect()
+	}
+}
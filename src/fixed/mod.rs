@@ -1,11 +1,41 @@
 #[cfg(test)]
 mod test;
 
+mod aligned;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "bytes")]
+mod buf_mut;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod collection;
+mod drain;
+mod ffi;
 mod into_iter;
+mod len;
+mod map;
+#[cfg(feature = "rayon")]
+pub(crate) mod par_iter;
+mod slice;
+mod storage;
+mod write;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 
-pub use collection::Fixed;
-pub use into_iter::IntoIter;
+pub use aligned::{Align128, Align16, Align32, Align64, Aligned, Alignment, FixedAligned};
+#[cfg(feature = "alloc")]
+pub use boxed::FixedBox;
+pub use collection::{Fixed, FixedIn};
+pub use drain::Drain;
+pub use ffi::{CArray, FixedFfi, RawParts, C_HEADER};
+pub use into_iter::{IntoIter, IntoIterIn};
+pub use len::{Len, Length};
+#[cfg(feature = "rayon")]
+pub use par_iter::IntoParIter;
+pub use slice::FixedSlice;
+pub use storage::{InlineStorage, Storage};
 
 #[macro_export]
 macro_rules! fixed {
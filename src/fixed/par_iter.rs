@@ -0,0 +1,208 @@
+use core::{iter::FusedIterator, slice::IterMut};
+
+use rayon::iter::{
+	plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+	IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+};
+
+use super::collection::Fixed;
+
+/// A by-value parallel iterator over the elements of a [`Fixed`].
+#[derive(Debug)]
+pub struct IntoParIter<T, const N: usize> {
+	list: Fixed<T, N>,
+}
+
+impl<T: Send, const N: usize> IntoParallelIterator for Fixed<T, N> {
+	type Iter = IntoParIter<T, N>;
+	type Item = T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		IntoParIter { list: self }
+	}
+}
+
+impl<'a, T: Sync, const N: usize> IntoParallelIterator for &'a Fixed<T, N> {
+	type Iter = rayon::slice::Iter<'a, T>;
+	type Item = &'a T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.as_slice().into_par_iter()
+	}
+}
+
+impl<'a, T: Send, const N: usize> IntoParallelIterator for &'a mut Fixed<T, N> {
+	type Iter = rayon::slice::IterMut<'a, T>;
+	type Item = &'a mut T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.as_mut_slice().into_par_iter()
+	}
+}
+
+impl<T: Send, const N: usize> ParallelIterator for IntoParIter<T, N> {
+	type Item = T;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.list.len())
+	}
+}
+
+impl<T: Send, const N: usize> IndexedParallelIterator for IntoParIter<T, N> {
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn len(&self) -> usize {
+		self.list.len()
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+		let len = self.list.len();
+
+		// SAFETY: The elements are handed over to the producer, which drops
+		// whatever it does not yield, so the list must forget them first.
+		unsafe {
+			self.list.set_len(0);
+
+			let slice = core::slice::from_raw_parts_mut(self.list.as_mut_ptr(), len);
+
+			callback.callback(DrainProducer::new(slice))
+		}
+	}
+}
+
+impl<T: Send, const N: usize> Fixed<T, N> {
+	/// Collects a parallel iterator into a list, preserving the order of the
+	/// elements.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with an element that did not fit if the iterator yields
+	/// more than `N` elements.
+	pub fn try_from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Result<Self, T> {
+		par_iter
+			.into_par_iter()
+			.try_fold(Self::new, |mut list, value| {
+				list.try_push(value).map(|()| list)
+			})
+			.try_reduce(Self::new, |mut list, other| {
+				for value in other {
+					list.try_push(value)?;
+				}
+
+				Ok(list)
+			})
+	}
+}
+
+/// A producer that owns the elements of a borrowed slice, moving them out as
+/// they are yielded and dropping the rest.
+pub(crate) struct DrainProducer<'a, T: Send> {
+	slice: &'a mut [T],
+}
+
+impl<'a, T: Send> DrainProducer<'a, T> {
+	/// Creates a producer over the elements of `slice`.
+	///
+	/// # Safety
+	///
+	/// The elements must not be used or dropped by anyone else once the
+	/// producer is created.
+	#[inline]
+	pub(crate) unsafe fn new(slice: &'a mut [T]) -> Self {
+		Self { slice }
+	}
+}
+
+impl<'a, T: Send> Producer for DrainProducer<'a, T> {
+	type Item = T;
+	type IntoIter = SliceDrain<'a, T>;
+
+	#[inline]
+	fn into_iter(mut self) -> Self::IntoIter {
+		let slice = core::mem::take(&mut self.slice);
+
+		SliceDrain {
+			iter: slice.iter_mut(),
+		}
+	}
+
+	#[inline]
+	fn split_at(mut self, index: usize) -> (Self, Self) {
+		let slice = core::mem::take(&mut self.slice);
+		let (left, right) = slice.split_at_mut(index);
+
+		(Self { slice: left }, Self { slice: right })
+	}
+}
+
+impl<T: Send> Drop for DrainProducer<'_, T> {
+	#[inline]
+	fn drop(&mut self) {
+		let slice = core::mem::take(&mut self.slice);
+
+		// SAFETY: The producer owns the elements it has not handed out.
+		unsafe { core::ptr::drop_in_place(slice) };
+	}
+}
+
+/// A sequential iterator moving the elements out of a [`DrainProducer`].
+pub(crate) struct SliceDrain<'a, T> {
+	iter: IterMut<'a, T>,
+}
+
+impl<T> Iterator for SliceDrain<'_, T> {
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// SAFETY: Each element is yielded at most once and is owned by the
+		// iterator until then.
+		self.iter
+			.next()
+			.map(|value| unsafe { core::ptr::read(value) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl<T> DoubleEndedIterator for SliceDrain<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		// SAFETY: Each element is yielded at most once and is owned by the
+		// iterator until then.
+		self.iter
+			.next_back()
+			.map(|value| unsafe { core::ptr::read(value) })
+	}
+}
+
+impl<T> ExactSizeIterator for SliceDrain<'_, T> {}
+
+impl<T> FusedIterator for SliceDrain<'_, T> {}
+
+impl<T> Drop for SliceDrain<'_, T> {
+	#[inline]
+	fn drop(&mut self) {
+		let iter = core::mem::take(&mut self.iter);
+
+		// SAFETY: The iterator owns the elements it has not yielded.
+		unsafe { core::ptr::drop_in_place(iter.into_slice()) };
+	}
+}
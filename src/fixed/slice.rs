@@ -0,0 +1,19 @@
+use core::mem::MaybeUninit;
+
+use super::collection::FixedIn;
+
+/// A contiguous list of elements of type `T` stored in a borrowed buffer.
+///
+/// This is [`Fixed`](super::Fixed) for a buffer the caller already has, such as
+/// a slice carved from an arena, whose capacity is only known at runtime.
+/// Dropping the list drops its elements but leaves the buffer to its owner.
+pub type FixedSlice<'a, T> = FixedIn<&'a mut [MaybeUninit<T>]>;
+
+impl<'a, T> FixedSlice<'a, T> {
+	/// Constructs a new, empty list over the given buffer.
+	#[inline]
+	#[must_use]
+	pub fn new(inner: &'a mut [MaybeUninit<T>]) -> Self {
+		Self::from_storage(inner)
+	}
+}
@@ -0,0 +1,122 @@
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use super::len::{Len, Length};
+
+/// The buffer a [`FixedIn`](super::FixedIn) list keeps its elements in.
+///
+/// Implementing this trait gives a buffer the whole fixed-capacity list API,
+/// including [`IntoIterIn`](super::IntoIterIn) and [`Drain`](super::Drain).
+///
+/// # Safety
+///
+/// [`as_ptr`](Storage::as_ptr) and [`as_mut_ptr`](Storage::as_mut_ptr) must
+/// point to [`capacity`](Storage::capacity) properly aligned slots of
+/// `Self::Item`, which keep their contents when the storage is moved. The
+/// capacity must never change.
+pub unsafe trait Storage {
+	/// The type of the elements.
+	type Item;
+
+	/// The type the list stores its length as.
+	type Len: Length;
+
+	/// Returns the number of elements the buffer can hold.
+	fn capacity(&self) -> usize;
+
+	/// Returns a pointer to the first slot of the buffer.
+	fn as_ptr(&self) -> *const Self::Item;
+
+	/// Returns a mutable pointer to the first slot of the buffer.
+	fn as_mut_ptr(&mut self) -> *mut Self::Item;
+}
+
+/// A [`Storage`] kept inline in the list, which can be created in a `const`
+/// context without initializing it.
+///
+/// Lists over inline storage get `const` constructors, and a
+/// [`ResizableIn`](crate::resizable::ResizableIn) can keep its elements in one
+/// until they spill to the heap.
+///
+/// # Safety
+///
+/// [`UNINIT`](InlineStorage::UNINIT) must have a capacity of exactly
+/// [`CAPACITY`](InlineStorage::CAPACITY).
+pub unsafe trait InlineStorage: Storage {
+	/// The number of elements the buffer can hold.
+	const CAPACITY: usize;
+
+	/// A buffer whose slots are all uninitialized.
+	const UNINIT: Self;
+}
+
+/// An inline array, which stores its length in a single byte.
+unsafe impl<T, const N: usize> Storage for [MaybeUninit<T>; N] {
+	type Item = T;
+	type Len = Len;
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		N
+	}
+
+	#[inline]
+	fn as_ptr(&self) -> *const T {
+		<[MaybeUninit<T>]>::as_ptr(self).cast()
+	}
+
+	#[inline]
+	fn as_mut_ptr(&mut self) -> *mut T {
+		<[MaybeUninit<T>]>::as_mut_ptr(self).cast()
+	}
+}
+
+unsafe impl<T, const N: usize> InlineStorage for [MaybeUninit<T>; N] {
+	const CAPACITY: usize = N;
+	const UNINIT: Self = [const { MaybeUninit::uninit() }; N];
+}
+
+/// A borrowed slice, which is left to its owner when the list is dropped.
+unsafe impl<T> Storage for &mut [MaybeUninit<T>] {
+	type Item = T;
+	type Len = usize;
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn as_ptr(&self) -> *const T {
+		<[MaybeUninit<T>]>::as_ptr(self).cast()
+	}
+
+	#[inline]
+	fn as_mut_ptr(&mut self) -> *mut T {
+		<[MaybeUninit<T>]>::as_mut_ptr(self).cast()
+	}
+}
+
+/// A boxed slice, whose capacity is chosen when it is allocated.
+#[cfg(feature = "alloc")]
+unsafe impl<T> Storage for Box<[MaybeUninit<T>]> {
+	type Item = T;
+	type Len = usize;
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn as_ptr(&self) -> *const T {
+		<[MaybeUninit<T>]>::as_ptr(self).cast()
+	}
+
+	#[inline]
+	fn as_mut_ptr(&mut self) -> *mut T {
+		<[MaybeUninit<T>]>::as_mut_ptr(self).cast()
+	}
+}
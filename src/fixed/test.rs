@@ -1,6 +1,17 @@
-use alloc::{boxed::Box, vec};
+use core::{cell::Cell, mem::MaybeUninit, panic::AssertUnwindSafe};
 
-use crate::fixed::Fixed;
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::fixed::{Fixed, FixedSlice};
+
+/// Counts its drops in a shared counter, tagged with a value to tell it apart.
+struct Counted<'a>(&'a Cell<usize>, u64);
+
+impl Drop for Counted<'_> {
+	fn drop(&mut self) {
+		self.0.set(self.0.get() + 1);
+	}
+}
 
 #[test]
 fn test_push_elements() {
@@ -46,6 +57,7 @@ fn test_insert_elements() {
 	assert_eq!(fixed.as_slice(), &[2, 4, 3, 1]);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn test_to_vec_reserve() {
 	let mut fixed = Fixed::<u64, 4>::new();
@@ -62,7 +74,7 @@ fn test_to_vec_reserve() {
 	assert_eq!(fixed.len(), 3);
 	assert_eq!(fixed.try_push(4), Ok(()));
 
-	assert_eq!(fixed.to_vec_reserve(1), vec![1, 2, 3, 4]);
+	assert_eq!(fixed.to_vec_reserve(1), alloc::vec![1, 2, 3, 4]);
 }
 
 #[test]
@@ -112,6 +124,28 @@ fn test_remove_elements() {
 	assert_eq!(fixed.try_remove(0), None);
 }
 
+#[test]
+fn test_niche() {
+	use core::mem::size_of;
+
+	assert_eq!(size_of::<Option<Fixed<u8, 4>>>(), size_of::<Fixed<u8, 4>>());
+	assert_eq!(
+		size_of::<Result<Fixed<u64, 4>, ()>>(),
+		size_of::<Fixed<u64, 4>>()
+	);
+
+	let mut fixed = Some(Fixed::<u8, 254>::from_iter(0..=253));
+
+	assert_eq!(fixed.as_mut().map(|fixed| fixed.len()), Some(254));
+	assert_eq!(fixed.and_then(|mut fixed| fixed.try_pop()), Some(253));
+}
+
+#[test]
+#[should_panic = "`Fixed` capacity exceeds 254"]
+fn test_capacity_limit() {
+	let _ = Fixed::<u8, 255>::new();
+}
+
 #[test]
 fn test_clear() {
 	let mut fixed = Fixed::<Box<u64>, 128>::new();
@@ -145,3 +179,566 @@ fn test_into_iter() {
 	assert_eq!(iter.next(), None);
 	assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_truncate() {
+	let mut fixed = Fixed::<Box<u64>, 4>::new();
+
+	for index in 0..4 {
+		assert_eq!(fixed.try_push(index.into()), Ok(()));
+	}
+
+	fixed.truncate(5);
+
+	assert_eq!(fixed.len(), 4);
+
+	fixed.truncate(1);
+
+	assert_eq!(fixed.as_slice(), &[0.into()]);
+}
+
+#[test]
+fn test_into_iter_slices() {
+	let mut iter = Fixed::<u64, 4>::from_iter([1, 2, 3, 4]).into_iter();
+
+	assert_eq!(iter.as_slice(), &[1, 2, 3, 4]);
+	assert_eq!(iter.next(), Some(1));
+
+	iter.as_mut_slice()[0] = 5;
+
+	assert_eq!(iter.as_slice(), &[5, 3, 4]);
+	assert_eq!(
+		iter.clone().collect::<Fixed<u64, 4>>().as_slice(),
+		&[5, 3, 4]
+	);
+	assert_eq!(alloc::format!("{iter:?}"), "IntoIter([5, 3, 4])");
+}
+
+#[test]
+fn test_into_iter_skip() {
+	let list = Fixed::<Box<u64>, 8>::from_iter((0..8).map(Box::new));
+	let mut iter = list.into_iter();
+
+	assert_eq!(iter.nth(1), Some(1.into()));
+	assert_eq!(iter.nth_back(1), Some(6.into()));
+	assert_eq!(iter.advance_by(2), Ok(()));
+	assert_eq!(iter.as_slice(), &[4.into(), 5.into()]);
+	assert_eq!(iter.clone().fold(0, |accum, item| accum * 10 + *item), 45);
+	assert_eq!(iter.clone().rfold(0, |accum, item| accum * 10 + *item), 54);
+	assert_eq!(iter.advance_back_by(3).map_err(usize::from), Err(1));
+	assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_retain() {
+	let mut fixed = Fixed::<Box<u64>, 8>::from_iter((0..8).map(Box::new));
+
+	fixed.retain(|item| **item % 3 != 0);
+
+	assert_eq!(fixed.as_slice(), &[1, 2, 4, 5, 7].map(Box::new));
+
+	fixed.retain_mut(|item| {
+		**item *= 2;
+
+		**item > 4
+	});
+
+	assert_eq!(fixed.as_slice(), &[8, 10, 14].map(Box::new));
+}
+
+#[test]
+fn test_retain_panic() {
+	let drops = Cell::new(0);
+	let mut fixed = Fixed::<Counted, 8>::from_iter((0..8).map(|value| Counted(&drops, value)));
+	let mut visited = 0;
+
+	let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+		fixed.retain(|_| {
+			visited += 1;
+
+			assert!(visited != 5, "predicate panicked");
+
+			visited % 2 == 0
+		});
+	}));
+
+	assert!(result.is_err());
+	assert_eq!(drops.get(), 2);
+	assert_eq!(fixed.len(), 6);
+
+	drop(fixed);
+
+	assert_eq!(drops.get(), 8);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic = "capacity overflow"]
+fn test_to_vec_reserve_overflow() {
+	let fixed = Fixed::<u8, 16>::from_iter(0..10);
+
+	let _ = fixed.to_vec_reserve(usize::MAX - 5);
+}
+
+#[test]
+fn test_const_accessors() {
+	const fn summary(list: &Fixed<u8, 4>) -> (usize, usize, bool, *const u8) {
+		(
+			list.len(),
+			list.capacity(),
+			list.as_slice().is_empty(),
+			list.as_ptr(),
+		)
+	}
+
+	let fixed = Fixed::<u8, 4>::from_iter([1, 2]);
+
+	assert_eq!(summary(&fixed), (2, 4, false, fixed.as_ptr()));
+}
+
+#[test]
+fn test_map() {
+	let fixed = Fixed::<u8, 4>::from_iter(1..=3);
+
+	assert_eq!(fixed.clone().map(u32::from).as_slice(), &[1, 2, 3]);
+	assert_eq!(
+		fixed
+			.clone()
+			.try_map(|x| x.checked_sub(1).ok_or(x))
+			.unwrap()
+			.as_slice(),
+		&[0, 1, 2]
+	);
+	assert_eq!(
+		fixed
+			.try_map(|x| if x == 2 { Err(x) } else { Ok(x) })
+			.unwrap_err(),
+		2
+	);
+
+	let drops = Cell::new(0);
+	let fixed = Fixed::<Counted, 4>::from_iter((0..4).map(|value| Counted(&drops, value)));
+	let mut visited = 0;
+
+	let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+		fixed.map(|value| {
+			visited += 1;
+
+			assert!(visited != 3, "mapping panicked");
+
+			value
+		})
+	}));
+
+	assert!(result.is_err());
+	assert_eq!(drops.get(), 4);
+
+	let pairs = Fixed::<u8, 3>::from_iter([1, 2]).zip(Fixed::from_iter(['a', 'b']));
+
+	assert_eq!(pairs.as_slice(), &[(1, 'a'), (2, 'b')]);
+}
+
+#[test]
+#[should_panic = "`zip` called on lists of different lengths"]
+fn test_zip_lengths() {
+	let _ = Fixed::<u8, 3>::from_iter([1, 2]).zip(Fixed::<u8, 3>::from_iter([1]));
+}
+
+#[test]
+fn test_slice() {
+	let mut buffer = [const { MaybeUninit::uninit() }; 6];
+	let mut slice = FixedSlice::<Box<u64>>::new(&mut buffer[1..5]);
+
+	assert_eq!(slice.capacity(), 4);
+	assert_eq!(slice.try_push(Box::new(1)), Ok(()));
+	assert_eq!(slice.try_insert(0, Box::new(2)), Ok(()));
+	assert_eq!(slice.try_insert(3, Box::new(3)), Err(Box::new(3)));
+
+	slice.extend([3, 4].map(Box::new));
+
+	assert_eq!(slice.try_push(Box::new(5)), Err(Box::new(5)));
+	assert_eq!(slice.try_remove(1), Some(Box::new(1)));
+	assert_eq!(slice.try_swap_remove(0), Some(Box::new(2)));
+	assert_eq!(slice.as_slice(), &[4, 3].map(Box::new));
+
+	slice.retain(|item| **item != 4);
+
+	assert_eq!(slice.try_pop(), Some(Box::new(3)));
+	assert!(slice.is_empty());
+}
+
+#[test]
+fn test_slice_drain() {
+	let drops = Cell::new(0);
+	let mut buffer = [const { MaybeUninit::uninit() }; 8];
+	let mut slice = FixedSlice::new(&mut buffer);
+
+	slice.extend((0..8).map(|value| Counted(&drops, value)));
+
+	let mut drain = slice.drain(2..6);
+
+	assert_eq!(drain.len(), 4);
+	assert_eq!(drain.next().map(|item| item.1), Some(2));
+	assert_eq!(drain.next_back().map(|item| item.1), Some(5));
+
+	drop(drain);
+
+	assert_eq!(drops.get(), 4);
+	assert_eq!(
+		slice.iter().map(|item| item.1).collect::<Vec<_>>(),
+		[0, 1, 6, 7]
+	);
+
+	core::mem::forget(slice.drain(1..));
+
+	assert_eq!(slice.len(), 1);
+	assert_eq!(slice.drain(..).map(|item| item.1).collect::<Vec<_>>(), [0]);
+	assert!(std::panic::catch_unwind(AssertUnwindSafe(|| drop(slice.drain(..1)))).is_err());
+
+	slice.extend((0..2).map(|value| Counted(&drops, value)));
+	drop(slice);
+
+	assert_eq!(drops.get(), 7);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_storage() {
+	use crate::fixed::FixedBox;
+
+	let mut boxed = FixedBox::with_capacity(300);
+
+	boxed.extend(0..300);
+
+	assert_eq!(boxed.try_push(300), Err(300));
+	assert_eq!(boxed.drain(1..299).len(), 298);
+	assert_eq!(boxed.into_iter().collect::<Vec<_>>(), [0, 299]);
+
+	let mut fixed = Fixed::<Box<u64>, 4>::from_iter((0..4).map(Box::new));
+
+	assert_eq!(
+		fixed.drain(..2).rev().collect::<Vec<_>>(),
+		[1, 0].map(Box::new)
+	);
+	assert_eq!(fixed.as_slice(), &[2, 3].map(Box::new));
+
+	let mut buffer = [const { MaybeUninit::uninit() }; 4];
+	let mut slice = FixedSlice::new(&mut buffer);
+
+	slice.extend((0..3).map(Box::new));
+
+	let mut iter = slice.into_iter();
+
+	assert_eq!(iter.next_back(), Some(Box::new(2)));
+	assert_eq!(iter.as_slice(), &[0, 1].map(Box::new));
+
+	drop(iter);
+
+	let storage = FixedSlice::new(&mut buffer).into_storage();
+
+	assert_eq!(storage.len(), 4);
+}
+
+#[test]
+fn test_aligned() {
+	use crate::fixed::{Align64, FixedAligned};
+
+	let mut lists = [const { FixedAligned::<f32, 8, Align64>::new() }; 3];
+
+	for list in &mut lists {
+		assert_eq!(list.as_ptr() as usize % 64, 0);
+
+		list.extend([1.0, 2.0, 3.0]);
+
+		assert_eq!(list.as_ptr() as usize % 64, 0);
+	}
+
+	assert_eq!(core::mem::align_of::<FixedAligned<u8, 8, Align64>>(), 64);
+	assert_eq!(lists[2].iter().sum::<f32>(), 6.0);
+	assert_eq!(
+		lists[2].clone().into_iter().rev().collect::<Vec<_>>(),
+		[3.0, 2.0, 1.0]
+	);
+}
+
+#[test]
+fn test_ffi() {
+	use core::mem::{offset_of, size_of};
+
+	use crate::fixed::{FixedFfi, RawParts, C_HEADER};
+
+	assert_eq!(size_of::<FixedFfi<u16, 3>>(), size_of::<usize>() * 2);
+	assert_eq!(offset_of!(RawParts<u16>, len), size_of::<usize>());
+	assert_eq!(offset_of!(RawParts<u16>, cap), size_of::<usize>() * 2);
+	assert!(C_HEADER.contains("#define LIST_FIXED(T, N)"));
+
+	let mut fixed = FixedFfi::<u16, 3>::new();
+
+	fixed.extend([1, 2]);
+
+	let base: *const FixedFfi<u16, 3> = &fixed;
+
+	assert_eq!(unsafe { base.cast::<usize>().read() }, 2);
+	assert_eq!(
+		unsafe { base.cast::<usize>().add(1).cast() },
+		fixed.as_ptr()
+	);
+
+	let parts = fixed.as_raw_parts();
+
+	// Stands in for C code appending an element in place.
+	unsafe {
+		parts.ptr.add(parts.len).write(3);
+		fixed.set_len(parts.len + 1);
+	}
+
+	assert_eq!((parts.len, parts.cap), (2, 3));
+	assert_eq!(fixed.as_slice(), &[1, 2, 3]);
+
+	let mut buffer = [const { MaybeUninit::uninit() }; 4];
+	let mut slice = FixedSlice::new(&mut buffer);
+
+	slice.extend((0..2).map(Box::new));
+
+	let parts = slice.into_raw_parts();
+
+	assert_eq!((parts.len, parts.cap), (2, 4));
+
+	let mut slice = unsafe { FixedSlice::from_raw_parts(parts) };
+
+	assert_eq!(slice.try_pop(), Some(Box::new(1)));
+	assert_eq!(slice.as_slice(), &[Box::new(0)]);
+
+	let empty = unsafe {
+		FixedSlice::<u8>::from_raw_parts(RawParts {
+			ptr: core::ptr::null_mut(),
+			len: 0,
+			cap: 0,
+		})
+	};
+
+	assert_eq!(empty.capacity(), 0);
+}
+
+#[test]
+#[should_panic = "`len` exceeds `cap`"]
+fn test_ffi_invalid_parts() {
+	use crate::fixed::RawParts;
+
+	let mut buffer = [0u8; 2];
+
+	let _ = unsafe {
+		FixedSlice::from_raw_parts(RawParts {
+			ptr: buffer.as_mut_ptr(),
+			len: 3,
+			cap: 2,
+		})
+	};
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_bytemuck() {
+	use crate::fixed::FixedFfi;
+
+	let empty: FixedFfi<u32, 4> = bytemuck::Zeroable::zeroed();
+
+	assert!(empty.is_empty());
+
+	let mut words = [0usize; 1 + 16 / size_of::<usize>()];
+
+	words[0] = 2;
+	words[1] = usize::from_ne_bytes([7; size_of::<usize>()]);
+
+	let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+	let list = FixedFfi::<u32, 4>::from_bytes(&bytes[..]).unwrap();
+
+	assert_eq!(list.as_slice(), &[0x0707_0707; 2]);
+	assert!(FixedFfi::<u32, 4>::from_bytes(&bytes[1..]).is_none());
+
+	bytes[..size_of::<usize>()].copy_from_slice(&5usize.to_ne_bytes());
+
+	assert!(FixedFfi::<u32, 4>::from_bytes(&bytes[..]).is_none());
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn test_zerocopy() {
+	use zerocopy::{Immutable, KnownLayout};
+
+	use crate::fixed::FixedFfi;
+
+	fn assert_layout<T: Immutable + KnownLayout + ?Sized>() {}
+
+	assert_layout::<FixedFfi<f32, 16>>();
+}
+
+#[test]
+fn test_clone_from() {
+	let source = Fixed::<Vec<u64>, 3>::from_iter([alloc::vec![1], alloc::vec![2, 3]]);
+
+	for len in 0..=3 {
+		let mut fixed = Fixed::<Vec<u64>, 3>::from_iter((0..len).map(|_| Vec::with_capacity(4)));
+		let pointers = fixed.iter().map(Vec::as_ptr).collect::<Vec<_>>();
+
+		fixed.clone_from(&source);
+
+		assert_eq!(fixed.as_slice(), source.as_slice());
+
+		for (value, pointer) in fixed.iter().zip(pointers) {
+			assert_eq!(value.as_ptr(), pointer);
+		}
+	}
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_par_iter() {
+	use alloc::sync::Arc;
+
+	use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+	let mut fixed = (1..=8).collect::<Fixed<u64, 8>>();
+
+	(&mut fixed).into_par_iter().for_each(|value| *value *= 2);
+
+	assert_eq!((&fixed).into_par_iter().sum::<u64>(), 72);
+	assert_eq!(
+		fixed.into_par_iter().rev().collect::<Vec<_>>(),
+		[16, 14, 12, 10, 8, 6, 4, 2]
+	);
+
+	let shared = Arc::new(());
+	let fixed = Fixed::<_, 8>::from_iter(core::iter::repeat_n(shared.clone(), 8));
+	let taken = fixed
+		.into_par_iter()
+		.with_max_len(1)
+		.skip(2)
+		.take(3)
+		.collect::<Vec<_>>();
+
+	assert_eq!(Arc::strong_count(&shared), 4);
+
+	drop(taken);
+
+	assert_eq!(Arc::strong_count(&shared), 1);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_try_from_par_iter() {
+	use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+	let fixed = Fixed::<u64, 4>::try_from_par_iter((0..4).into_par_iter().map(|value| value * 2));
+
+	assert_eq!(fixed.unwrap().as_slice(), &[0, 2, 4, 6]);
+	assert!(Fixed::<u64, 4>::try_from_par_iter(0..5_u64).is_err());
+	assert!(Fixed::<u64, 4>::try_from_par_iter(
+		(0..100_u64).into_par_iter().filter(|value| value % 25 == 0)
+	)
+	.is_ok());
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn test_arbitrary() {
+	use arbitrary::{Arbitrary, Unstructured};
+
+	let bytes = [1, 10, 1, 20, 1, 30, 1, 40, 0];
+
+	let fixed = Fixed::<u8, 2>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+	assert_eq!(fixed.as_slice(), &[10, 20]);
+
+	let fixed = Fixed::<u8, 2>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+
+	assert_eq!(fixed.len(), 2);
+}
+
+#[test]
+fn test_fmt_write() {
+	use core::fmt::Write;
+
+	let mut fixed = Fixed::<u8, 8>::new();
+
+	assert!(write!(fixed, "{}-{}", 12, 3).is_ok());
+	assert_eq!(fixed.as_slice(), b"12-3");
+	assert!(fixed.write_str("fives").is_err());
+	assert_eq!(fixed.as_slice(), b"12-3");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_io_write() {
+	use std::io::{ErrorKind, Write};
+
+	let mut fixed = Fixed::<u8, 4>::new();
+
+	assert_eq!(fixed.write(b"abc").unwrap(), 3);
+	assert_eq!(fixed.write(b"de").unwrap(), 1);
+	assert_eq!(fixed.write(b"f").unwrap(), 0);
+	assert_eq!(
+		fixed.write_all(b"g").unwrap_err().kind(),
+		ErrorKind::WriteZero
+	);
+	assert_eq!(fixed.as_slice(), b"abcd");
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn test_buf_mut() {
+	use bytes::BufMut;
+
+	let mut fixed = Fixed::<u8, 4>::new();
+
+	fixed.put_u8(1);
+
+	assert_eq!(fixed.remaining_mut(), 3);
+	assert_eq!(fixed.chunk_mut().len(), 3);
+
+	fixed.put_slice(&[2, 3, 4]);
+
+	assert!(!fixed.has_remaining_mut());
+	assert_eq!(fixed.as_slice(), &[1, 2, 3, 4]);
+	assert!(std::panic::catch_unwind(AssertUnwindSafe(|| fixed.put_u8(5))).is_err());
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn test_zeroize() {
+	use zeroize::Zeroize;
+
+	let is_wiped =
+		|buffer: &[MaybeUninit<u8>]| (buffer.iter()).all(|slot| unsafe { slot.assume_init() } == 0);
+
+	let mut fixed = Fixed::<u8, 4>::from_iter([1, 2, 3, 4]);
+
+	fixed.try_pop();
+	fixed.zeroize();
+
+	assert!(fixed.is_empty());
+	assert!(is_wiped(fixed.spare_capacity_mut()));
+
+	let mut buffer = [MaybeUninit::new(0xff); 4];
+	let mut slice = FixedSlice::new(&mut buffer);
+
+	slice.extend([1, 2, 3]);
+	slice.try_remove(0);
+
+	drop(slice);
+
+	assert!(is_wiped(&buffer));
+
+	let mut buffer = [MaybeUninit::new(0xff); 4];
+	let mut slice = FixedSlice::new(&mut buffer);
+
+	slice.extend([1, 2, 3]);
+
+	let mut iter = slice.into_iter();
+
+	assert_eq!(iter.next(), Some(1));
+
+	drop(iter);
+
+	assert!(is_wiped(&buffer));
+}
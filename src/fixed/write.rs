@@ -0,0 +1,65 @@
+use core::fmt;
+
+use crate::traits::List;
+
+use super::{collection::FixedIn, storage::Storage};
+
+impl<S: Storage<Item = u8>> FixedIn<S> {
+	/// Appends as many bytes of `bytes` as fit in the spare capacity, and
+	/// returns how many were appended.
+	pub(super) fn write_bytes(&mut self, bytes: &[u8]) -> usize {
+		let len = self.len();
+		let count = bytes.len().min(self.capacity() - len);
+
+		// SAFETY: `count` bytes fit in the spare capacity.
+		unsafe {
+			self.as_mut_ptr()
+				.add(len)
+				.copy_from_nonoverlapping(bytes.as_ptr(), count);
+			self.set_len(len + count);
+		}
+
+		count
+	}
+}
+
+/// Appends the UTF-8 bytes of the string, or returns an error without
+/// appending anything if they do not fit.
+impl<S: Storage<Item = u8>> fmt::Write for FixedIn<S> {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		if s.len() > self.capacity() - self.len() {
+			return Err(fmt::Error);
+		}
+
+		self.write_bytes(s.as_bytes());
+
+		Ok(())
+	}
+}
+
+/// Appends as many bytes as fit, so writes are short once the list is full and
+/// `write_all` reports [`WriteZero`](std::io::ErrorKind::WriteZero).
+///
+/// The bytes are read back through [`Cursor::new(list)`](std::io::Cursor::new).
+#[cfg(feature = "std")]
+impl<S: Storage<Item = u8>> std::io::Write for FixedIn<S> {
+	#[inline]
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		Ok(self.write_bytes(buf))
+	}
+
+	#[inline]
+	fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+		if self.write_bytes(buf) == buf.len() {
+			Ok(())
+		} else {
+			Err(std::io::ErrorKind::WriteZero.into())
+		}
+	}
+
+	#[inline]
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
@@ -0,0 +1,20 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::{collection::FixedIn, storage::Storage};
+
+/// Zeroes the elements, drops them and then zeroes the whole buffer,
+/// including slots left behind by earlier removals.
+impl<S: Storage> Zeroize for FixedIn<S>
+where
+	S::Item: Zeroize,
+{
+	#[inline]
+	fn zeroize(&mut self) {
+		self.iter_mut().zeroize();
+		self.clear();
+		self.spare_capacity_mut().zeroize();
+	}
+}
+
+/// Dropping a list zeroes its whole buffer once the elements are gone.
+impl<S: Storage> ZeroizeOnDrop for FixedIn<S> {}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod test;
+
+use core::{cell::Cell, marker::PhantomData};
+
+std::thread_local! {
+	static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A guard forbidding lists from allocating on the current thread until it is
+/// dropped.
+///
+/// While any guard is alive, a [`Resizable`] that would spill to the heap or
+/// reallocate its heap buffer panics in debug builds, and its fallible
+/// methods such as [`try_push`] and [`try_reserve`] return an error instead.
+/// Release builds are not affected, so the guard is meant to catch
+/// allocations in tests.
+///
+/// [`Resizable`]: crate::resizable::Resizable
+/// [`try_push`]: crate::resizable::ResizableIn::try_push
+/// [`try_reserve`]: crate::resizable::ResizableIn::try_reserve
+#[must_use = "allocation is only forbidden while the guard is alive"]
+#[derive(Debug)]
+pub struct AllocGuard {
+	_thread: PhantomData<*const ()>,
+}
+
+impl Drop for AllocGuard {
+	#[inline]
+	fn drop(&mut self) {
+		DEPTH.with(|depth| depth.set(depth.get() - 1));
+	}
+}
+
+/// Forbids lists from allocating on the current thread until the returned
+/// guard is dropped. Guards may be nested.
+#[inline]
+pub fn forbid_alloc() -> AllocGuard {
+	DEPTH.with(|depth| depth.set(depth.get() + 1));
+
+	AllocGuard {
+		_thread: PhantomData,
+	}
+}
+
+/// Returns `true` if allocation is forbidden on the current thread.
+#[inline]
+#[must_use]
+pub fn is_alloc_forbidden() -> bool {
+	DEPTH.with(Cell::get) != 0
+}
+
+/// Panics if allocation is forbidden on the current thread.
+///
+/// Callers only check in debug builds, as documented on [`AllocGuard`].
+#[inline]
+#[track_caller]
+pub(crate) fn check() {
+	#[cold]
+	#[track_caller]
+	fn forbidden() -> ! {
+		panic!("list allocated while allocation is forbidden on this thread");
+	}
+
+	if is_alloc_forbidden() {
+		forbidden();
+	}
+}
@@ -0,0 +1,136 @@
+use crate::{
+	compact::Compact,
+	guard::{forbid_alloc, is_alloc_forbidden},
+	resizable::Resizable,
+};
+
+#[test]
+fn test_nested() {
+	assert!(!is_alloc_forbidden());
+
+	let outer = forbid_alloc();
+	let inner = forbid_alloc();
+
+	drop(inner);
+
+	assert!(is_alloc_forbidden());
+
+	drop(outer);
+
+	assert!(!is_alloc_forbidden());
+}
+
+#[test]
+fn test_within_capacity() {
+	let mut resizable = Resizable::<u64, 2>::new();
+	let _guard = forbid_alloc();
+
+	assert_eq!(resizable.push_within_capacity(1), Ok(()));
+	assert_eq!(resizable.insert_within_capacity(0, 2), Ok(()));
+	assert_eq!(resizable.push_within_capacity(3), Err(3));
+	assert_eq!(resizable.insert_within_capacity(3, 3), Err(3));
+
+	resizable.pop();
+	resizable.push(3);
+
+	assert_eq!(resizable.as_slice(), &[2, 3]);
+}
+
+#[test]
+fn test_heap_within_capacity() {
+	let mut resizable = Resizable::<u64, 2>::with_capacity(4);
+	let _guard = forbid_alloc();
+
+	resizable.extend([1, 2, 3]);
+	resizable.reserve(1);
+
+	assert_eq!(resizable.push_within_capacity(4), Ok(()));
+	assert_eq!(resizable.push_within_capacity(5), Err(5));
+	assert_eq!(resizable.as_slice(), &[1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "allocation is forbidden")]
+fn test_spill() {
+	let mut resizable = Resizable::<u64, 2>::from_iter([1, 2]);
+	let _guard = forbid_alloc();
+
+	resizable.push(3);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "allocation is forbidden")]
+fn test_reallocate() {
+	let mut resizable = Resizable::<u64, 2>::from_iter(0..3);
+	let _guard = forbid_alloc();
+
+	resizable.reserve(resizable.capacity());
+}
+
+#[test]
+fn test_fallible() {
+	let mut resizable = Resizable::<u64, 2>::from_iter([1, 2]);
+	let guard = forbid_alloc();
+
+	assert_eq!(resizable.try_reserve(0), Ok(()));
+
+	if cfg!(debug_assertions) {
+		assert!(resizable.try_reserve(1).is_err());
+		assert_eq!(resizable.try_push(3), Err(3));
+		assert_eq!(resizable.try_insert(0, 3), Err(3));
+		assert!(matches!(resizable, Resizable::Fixed(..)));
+	}
+
+	drop(guard);
+
+	assert_eq!(resizable.try_push(3), Ok(()));
+	assert_eq!(resizable.try_reserve(8), Ok(()));
+
+	let _guard = forbid_alloc();
+
+	if cfg!(debug_assertions) {
+		assert!(resizable.try_reserve(resizable.capacity()).is_err());
+	}
+
+	assert_eq!(resizable.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "allocation is forbidden")]
+fn test_compact_spill() {
+	let mut compact = Compact::<u64, 2>::from_iter([1, 2]);
+	let _guard = forbid_alloc();
+
+	compact.push(3);
+}
+
+#[test]
+fn test_compact_fallible() {
+	let mut compact = Compact::<u64, 2>::with_capacity(4);
+	let _guard = forbid_alloc();
+
+	compact.extend(0..3);
+	compact.reserve(1);
+
+	assert_eq!(compact.try_push(3), Ok(()));
+
+	if cfg!(debug_assertions) {
+		assert!(compact.try_reserve(1).is_err());
+		assert_eq!(compact.try_push(4), Err(4));
+	}
+
+	assert_eq!(compact.as_slice(), &[0, 1, 2, 3]);
+}
+
+#[test]
+fn test_other_thread() {
+	let _guard = forbid_alloc();
+
+	std::thread::spawn(|| {
+		let mut resizable = Resizable::<u64, 2>::new();
+
+		resizable.extend(0..3);
+	})
+	.join()
+	.unwrap();
+}
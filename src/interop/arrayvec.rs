@@ -0,0 +1,39 @@
+use ::arrayvec::ArrayVec;
+
+use crate::fixed::Fixed;
+
+/// Fails with the original list if it holds more than `N` elements.
+impl<T, const N: usize, const M: usize> TryFrom<ArrayVec<T, M>> for Fixed<T, N> {
+	type Error = ArrayVec<T, M>;
+
+	#[inline]
+	fn try_from(mut list: ArrayVec<T, M>) -> Result<Self, Self::Error> {
+		if list.len() > N {
+			return Err(list);
+		}
+
+		let mut fixed = Self::new();
+
+		move_into!(list => fixed);
+
+		Ok(fixed)
+	}
+}
+
+/// Fails with the original list if it holds more than `M` elements.
+impl<T, const N: usize, const M: usize> TryFrom<Fixed<T, N>> for ArrayVec<T, M> {
+	type Error = Fixed<T, N>;
+
+	#[inline]
+	fn try_from(mut list: Fixed<T, N>) -> Result<Self, Self::Error> {
+		if list.len() > M {
+			return Err(list);
+		}
+
+		let mut vec = Self::new();
+
+		move_into!(list => vec);
+
+		Ok(vec)
+	}
+}
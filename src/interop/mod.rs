@@ -0,0 +1,54 @@
+//! Conversions between the lists of this crate and those of other crates.
+
+#[cfg(test)]
+mod test;
+
+#[cfg(any(feature = "arrayvec", feature = "heapless", feature = "smallvec"))]
+/// Moves the elements of `$list` into `$target`, which must have room for
+/// them, with a single copy.
+macro_rules! move_into {
+	($list:ident => $target:ident) => {{
+		let len = $list.len();
+
+		// SAFETY: The elements fit in the target and are no longer owned by
+		// the source once its length is cleared.
+		unsafe {
+			$target
+				.as_mut_ptr()
+				.copy_from_nonoverlapping($list.as_ptr(), len);
+			$list.set_len(0);
+			$target.set_len(len);
+		}
+	}};
+}
+
+/// Converts a vector of the allocator API into a standard one, keeping its
+/// buffer.
+#[cfg(any(feature = "smallvec", feature = "tinyvec"))]
+fn into_std_vec<T>(vec: allocator_api2::vec::Vec<T>) -> alloc::vec::Vec<T> {
+	let mut vec = core::mem::ManuallyDrop::new(vec);
+
+	// SAFETY: `Global` allocates through the global allocator, like the
+	// standard vector does.
+	unsafe { alloc::vec::Vec::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity()) }
+}
+
+/// Converts a standard vector into one of the allocator API, keeping its
+/// buffer.
+#[cfg(any(feature = "smallvec", feature = "tinyvec"))]
+fn from_std_vec<T>(vec: alloc::vec::Vec<T>) -> allocator_api2::vec::Vec<T> {
+	let mut vec = core::mem::ManuallyDrop::new(vec);
+
+	// SAFETY: `Global` allocates through the global allocator, like the
+	// standard vector does.
+	unsafe { allocator_api2::vec::Vec::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity()) }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
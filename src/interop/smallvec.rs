@@ -0,0 +1,50 @@
+use ::smallvec::SmallVec;
+use allocator_api2::{alloc::Global, vec::Vec};
+
+use crate::{
+	fixed::Fixed,
+	resizable::{Parts, Resizable},
+};
+
+use super::{from_std_vec, into_std_vec};
+
+/// Reuses the heap buffer of a spilled list, and copies inline elements
+/// otherwise.
+impl<T, const N: usize, const M: usize> From<SmallVec<[T; M]>> for Resizable<T, N> {
+	fn from(mut list: SmallVec<[T; M]>) -> Self {
+		if list.spilled() {
+			return Self::Heap(from_std_vec(list.into_vec()));
+		}
+
+		if list.len() <= N {
+			let mut fixed = Fixed::<T, N>::new();
+
+			move_into!(list => fixed);
+
+			Self::Fixed(fixed, Global)
+		} else {
+			let mut vec = Vec::<T>::with_capacity(list.len());
+
+			move_into!(list => vec);
+
+			Self::Heap(vec)
+		}
+	}
+}
+
+/// Reuses the heap buffer of a spilled list if it does not fit inline, and
+/// copies inline elements otherwise.
+impl<T, const N: usize, const M: usize> From<Resizable<T, N>> for SmallVec<[T; M]> {
+	fn from(list: Resizable<T, N>) -> Self {
+		match list.into_parts() {
+			Parts::Fixed(mut list, _) => {
+				let mut vec = Self::with_capacity(list.len());
+
+				move_into!(list => vec);
+
+				vec
+			}
+			Parts::Heap(vec) => Self::from_vec(into_std_vec(vec)),
+		}
+	}
+}
@@ -0,0 +1,111 @@
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+use crate::fixed::Fixed;
+#[cfg(any(feature = "smallvec", feature = "tinyvec"))]
+use crate::resizable::Resizable;
+
+#[test]
+#[cfg(feature = "arrayvec")]
+fn test_arrayvec() {
+	use arrayvec::ArrayVec;
+
+	let vec = ArrayVec::<u64, 4>::from_iter([1, 2, 3]);
+	let fixed = Fixed::<u64, 3>::try_from(vec).unwrap();
+
+	assert_eq!(fixed.as_slice(), &[1, 2, 3]);
+
+	let fixed = ArrayVec::<u64, 2>::try_from(fixed).unwrap_err();
+	let vec = ArrayVec::<u64, 3>::try_from(fixed).unwrap();
+
+	assert_eq!(vec.as_slice(), &[1, 2, 3]);
+	assert_eq!(
+		Fixed::<u64, 2>::try_from(vec).unwrap_err().as_slice(),
+		&[1, 2, 3]
+	);
+}
+
+#[test]
+#[cfg(feature = "heapless")]
+fn test_heapless() {
+	let vec = heapless::Vec::<u64, 4>::from_slice(&[1, 2, 3]).unwrap();
+	let fixed = Fixed::<u64, 3>::try_from(vec).unwrap();
+
+	assert_eq!(fixed.as_slice(), &[1, 2, 3]);
+
+	let fixed = heapless::Vec::<u64, 2>::try_from(fixed).unwrap_err();
+	let vec = heapless::Vec::<u64, 3>::try_from(fixed).unwrap();
+
+	assert_eq!(vec.as_slice(), &[1, 2, 3]);
+	assert_eq!(
+		Fixed::<u64, 2>::try_from(vec).unwrap_err().as_slice(),
+		&[1, 2, 3]
+	);
+}
+
+#[test]
+#[cfg(feature = "smallvec")]
+fn test_smallvec() {
+	use smallvec::SmallVec;
+
+	let vec = SmallVec::<[u64; 8]>::from_iter(0..4);
+	let resizable = Resizable::<u64, 2>::from(vec);
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+	assert!(resizable.iter().copied().eq(0..4));
+
+	let pointer = resizable.as_ptr();
+	let vec = SmallVec::<[u64; 2]>::from(resizable);
+
+	assert!(vec.spilled());
+	assert_eq!(vec.as_ptr(), pointer);
+
+	let resizable = Resizable::<u64, 2>::from(vec);
+
+	assert_eq!(resizable.as_ptr(), pointer);
+
+	let vec = SmallVec::<[u64; 4]>::from(Resizable::<u64, 2>::from_iter([1, 2]));
+
+	assert!(!vec.spilled());
+	assert_eq!(vec.as_slice(), &[1, 2]);
+
+	let resizable = Resizable::<u64, 2>::from(vec);
+
+	assert!(matches!(resizable, Resizable::Fixed(..)));
+	assert_eq!(resizable.as_slice(), &[1, 2]);
+}
+
+#[test]
+#[cfg(feature = "tinyvec")]
+fn test_tinyvec() {
+	use tinyvec::TinyVec;
+
+	let resizable = Resizable::<u64, 2>::from_iter(0..4);
+	let pointer = resizable.as_ptr();
+	let vec = TinyVec::<[u64; 2]>::from(resizable);
+
+	assert!(vec.is_heap());
+	assert_eq!(vec.as_ptr(), pointer);
+
+	let resizable = Resizable::<u64, 2>::from(vec);
+
+	assert_eq!(resizable.as_ptr(), pointer);
+
+	let vec = TinyVec::<[u64; 4]>::from(Resizable::<u64, 2>::from_iter([1, 2]));
+
+	assert!(vec.is_inline());
+
+	let resizable = Resizable::<u64, 1>::from(vec);
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+	assert_eq!(resizable.as_slice(), &[1, 2]);
+	assert!(TinyVec::<[u64; 1]>::from(resizable).is_heap());
+
+	let strings = ["a", "b"].map(alloc::string::String::from);
+	let vec = TinyVec::<[_; 4]>::from(Resizable::<_, 2>::from_iter(strings.clone()));
+
+	assert!(vec.is_inline());
+
+	let resizable = Resizable::<_, 2>::from(vec);
+
+	assert!(matches!(resizable, Resizable::Fixed(..)));
+	assert_eq!(resizable.as_slice(), &strings);
+}
@@ -0,0 +1,96 @@
+use core::{mem::ManuallyDrop, ptr};
+
+use ::tinyvec::{Array, ArrayVec, TinyVec};
+use allocator_api2::{alloc::Global, vec::Vec};
+
+use crate::{
+	fixed::Fixed,
+	resizable::{Parts, Resizable},
+};
+
+use super::{from_std_vec, into_std_vec};
+
+/// Reuses the heap buffer of a spilled list, and copies inline elements
+/// otherwise.
+impl<T: Default, const N: usize, const M: usize> From<TinyVec<[T; M]>> for Resizable<T, N>
+where
+	[T; M]: Array<Item = T>,
+{
+	fn from(list: TinyVec<[T; M]>) -> Self {
+		let list = match list {
+			TinyVec::Inline(list) => list,
+			TinyVec::Heap(vec) => return Self::Heap(from_std_vec(vec)),
+		};
+
+		let len = list.len();
+		let mut array = ManuallyDrop::new(list.into_inner());
+
+		let result = if len <= N {
+			let mut fixed = Fixed::<T, N>::new();
+
+			// SAFETY: The elements fit inline, and the array no longer owns them
+			// as it is never dropped.
+			unsafe {
+				fixed
+					.as_mut_ptr()
+					.copy_from_nonoverlapping(array.as_ptr(), len);
+				fixed.set_len(len);
+			}
+
+			Self::Fixed(fixed, Global)
+		} else {
+			let mut vec = Vec::<T>::with_capacity(len);
+
+			// SAFETY: The vector has room for the elements, and the array no
+			// longer owns them as it is never dropped.
+			unsafe {
+				vec.as_mut_ptr()
+					.copy_from_nonoverlapping(array.as_ptr(), len);
+				vec.set_len(len);
+			}
+
+			Self::Heap(vec)
+		};
+
+		// SAFETY: The slots after the length hold values that were not moved out.
+		unsafe { ptr::drop_in_place(&mut array[len..]) };
+
+		result
+	}
+}
+
+/// Reuses the heap buffer of a spilled list, and copies inline elements
+/// otherwise.
+impl<T: Default, const N: usize, const M: usize> From<Resizable<T, N>> for TinyVec<[T; M]>
+where
+	[T; M]: Array<Item = T>,
+{
+	fn from(list: Resizable<T, N>) -> Self {
+		match list.into_parts() {
+			Parts::Fixed(mut list, _) if list.len() <= M => {
+				let len = list.len();
+				let mut array = ManuallyDrop::new(<[T; M] as Array>::default());
+
+				// SAFETY: The default values in the first `len` slots are dropped
+				// before being overwritten, and the list no longer owns its
+				// elements once its length is cleared. The array is only taken
+				// out once every slot holds a value again.
+				unsafe {
+					ptr::drop_in_place(&mut array[..len]);
+
+					array
+						.as_mut_ptr()
+						.copy_from_nonoverlapping(list.as_ptr(), len);
+					list.set_len(0);
+				}
+
+				Self::Inline(ArrayVec::from_array_len(
+					ManuallyDrop::into_inner(array),
+					len,
+				))
+			}
+			Parts::Fixed(list, _) => Self::Heap(list.to_vec_reserve(0)),
+			Parts::Heap(vec) => Self::Heap(into_std_vec(vec)),
+		}
+	}
+}
@@ -1,6 +1,39 @@
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
+#![cfg_attr(
+	all(feature = "nightly", feature = "alloc-guard"),
+	feature(try_reserve_kind)
+)]
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "bytes")]
+pub mod buf;
+#[cfg(feature = "alloc")]
+pub mod compact;
 pub mod fixed;
+#[cfg(feature = "alloc-guard")]
+pub mod guard;
+#[cfg(any(
+	feature = "arrayvec",
+	feature = "heapless",
+	feature = "smallvec",
+	feature = "tinyvec"
+))]
+mod interop;
+#[cfg(all(feature = "alloc", any(test, feature = "fuzzing")))]
+#[doc(hidden)]
+pub mod model;
+#[cfg(feature = "alloc")]
 pub mod resizable;
+#[cfg(feature = "spill-stats")]
+pub mod spill;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod traits;
+
+pub use traits::{List, Push};
@@ -0,0 +1,232 @@
+use alloc::{rc::Rc, vec::Vec};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{fixed::Fixed, resizable::Resizable};
+
+use super::{
+	op::Op,
+	subject::{Bounded, Subject},
+	tracked::{Counter, Spec, Tracked},
+};
+
+/// The observable result of an operation.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+	Done,
+	Rejected(Spec),
+	Removed(Option<Spec>),
+	Copied(Vec<Spec>),
+	Panicked,
+}
+
+/// Reduces a raw index against `len`, leaving room for out-of-bounds values.
+#[inline]
+fn reduce(raw: u8, len: usize) -> usize {
+	usize::from(raw) % (len + 2)
+}
+
+fn specs(list: &[Tracked]) -> Vec<Spec> {
+	list.iter().map(Tracked::spec).collect()
+}
+
+/// A list together with the counter of its elements.
+struct Tracker<S> {
+	list: S,
+	counter: Rc<Counter>,
+}
+
+impl<S: Subject> Tracker<S> {
+	fn new() -> Self {
+		Self {
+			list: S::default(),
+			counter: Rc::default(),
+		}
+	}
+
+	/// Applies `op` to the list, catching any panic it causes.
+	fn apply(&mut self, op: &Op) -> Outcome {
+		let Self { list, counter } = self;
+		let len = list.len();
+
+		counter.arm(true);
+
+		let outcome = catch_unwind(AssertUnwindSafe(|| match *op {
+			Op::Push(spec) => match list.push(spec.create(counter)) {
+				Ok(()) => Outcome::Done,
+				Err(value) => Outcome::Rejected(value.spec()),
+			},
+			Op::Insert(index, spec) => {
+				match list.insert(reduce(index, len), spec.create(counter)) {
+					Ok(()) => Outcome::Done,
+					Err(value) => Outcome::Rejected(value.spec()),
+				}
+			}
+			Op::Extend(ref specs) => {
+				match list.extend(specs.iter().map(|spec| spec.create(counter))) {
+					Ok(()) => Outcome::Done,
+					Err(value) => Outcome::Rejected(value.spec()),
+				}
+			}
+			Op::Pop => Outcome::Removed(list.pop().map(|value| value.spec())),
+			Op::Remove(index) => {
+				Outcome::Removed(list.remove(reduce(index, len)).map(|value| value.spec()))
+			}
+			Op::SwapRemove(index) => Outcome::Removed(
+				list.swap_remove(reduce(index, len))
+					.map(|value| value.spec()),
+			),
+			Op::Truncate(new_len) => {
+				list.truncate(reduce(new_len, len));
+
+				Outcome::Done
+			}
+			Op::Clear => {
+				list.clear();
+
+				Outcome::Done
+			}
+			Op::Retain { keep, panic_at } => {
+				let mut visited = 0;
+
+				list.retain(|value| {
+					if panic_at == Some(visited) {
+						panic!("retain predicate at {visited}");
+					}
+
+					visited += 1;
+
+					keep.rotate_right(value.spec().value.into()) & 1 == 1
+				});
+
+				Outcome::Done
+			}
+			Op::Reserve(additional) => {
+				list.reserve(additional.into());
+
+				Outcome::Done
+			}
+			Op::Clone => {
+				let copy = list.clone();
+				let specs = specs(&copy);
+
+				// Dropping the copy may panic, which must happen before the
+				// outcome is built or the outcome would leak.
+				drop(copy);
+
+				Outcome::Copied(specs)
+			}
+			Op::CloneFrom(ref specs) => {
+				let mut copy = S::default();
+				let _ = copy.extend(specs.iter().map(|spec| spec.create(counter)));
+
+				copy.clone_from(list);
+
+				let specs = self::specs(&copy);
+
+				drop(copy);
+
+				Outcome::Copied(specs)
+			}
+			Op::IntoIter { front, back } => {
+				let mut iter = core::mem::take(list).into_iter();
+				let mut yielded = Vec::<Spec>::new();
+
+				yielded.extend(
+					iter.by_ref()
+						.take((front % 4).into())
+						.map(|value| value.spec()),
+				);
+				yielded.extend(
+					iter.by_ref()
+						.rev()
+						.take((back % 4).into())
+						.map(|value| value.spec()),
+				);
+
+				*list = S::from_iter(iter);
+
+				Outcome::Copied(yielded)
+			}
+		}))
+		.unwrap_or(Outcome::Panicked);
+
+		counter.arm(false);
+
+		outcome
+	}
+}
+
+/// A list under test and its oracle.
+struct Pair<S, O> {
+	subject: Tracker<S>,
+	oracle: Tracker<O>,
+}
+
+impl<S: Subject, O: Subject> Pair<S, O> {
+	fn new() -> Self {
+		Self {
+			subject: Tracker::new(),
+			oracle: Tracker::new(),
+		}
+	}
+
+	/// Applies `op` to both lists and checks that they still agree and that no
+	/// element was leaked or dropped twice.
+	#[track_caller]
+	fn step(&mut self, op: &Op) {
+		let expected = self.oracle.apply(op);
+		let outcome = self.subject.apply(op);
+		let list = &self.subject.list;
+
+		assert_eq!(outcome, expected, "outcome of {op:?}");
+		assert_eq!(
+			specs(list),
+			specs(&self.oracle.list),
+			"elements after {op:?}"
+		);
+		assert_eq!(
+			self.subject.counter.live(),
+			list.len(),
+			"live elements after {op:?}"
+		);
+		assert_eq!(self.oracle.counter.live(), self.oracle.list.len());
+		assert!(list.capacity() >= list.len(), "capacity after {op:?}");
+	}
+}
+
+/// Every list of the model with an inline capacity of `N`.
+pub(crate) struct Harness<const N: usize> {
+	fixed: Pair<Fixed<Tracked, N>, Bounded<N>>,
+	resizable: Pair<Resizable<Tracked, N>, Vec<Tracked>>,
+}
+
+impl<const N: usize> Harness<N> {
+	pub(crate) fn new() -> Self {
+		Self {
+			fixed: Pair::new(),
+			resizable: Pair::new(),
+		}
+	}
+
+	/// Applies `op` to every list and checks them against their oracles.
+	#[track_caller]
+	pub(crate) fn step(&mut self, op: &Op) {
+		self.fixed.step(op);
+		self.resizable.step(op);
+
+		if let Resizable::Fixed(list, _) = &self.resizable.subject.list {
+			assert!(list.len() <= N);
+		}
+	}
+
+	/// Drops every list, checking that no element is leaked.
+	pub(crate) fn finish(self) {
+		let fixed = Rc::clone(&self.fixed.subject.counter);
+		let resizable = Rc::clone(&self.resizable.subject.counter);
+
+		drop(self);
+
+		assert_eq!(fixed.live(), 0);
+		assert_eq!(resizable.live(), 0);
+	}
+}
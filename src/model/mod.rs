@@ -0,0 +1,35 @@
+//! A differential model of the lists, checking [`Fixed`] and [`Resizable`]
+//! against `Vec` over sequences of operations decoded from bytes.
+//!
+//! Elements count themselves to catch leaks and double drops, and some of them
+//! panic when dropped or cloned. The same entry point drives the unit tests,
+//! Miri and the fuzz target in `fuzz/`.
+//!
+//! [`Fixed`]: crate::fixed::Fixed
+//! [`Resizable`]: crate::resizable::Resizable
+
+#[cfg(test)]
+mod test;
+
+mod harness;
+mod op;
+mod subject;
+mod tracked;
+
+use harness::Harness;
+use op::Op;
+
+/// Runs the operations encoded in `data` against every list, panicking if a
+/// list disagrees with its oracle.
+pub fn run(mut data: &[u8]) {
+	let mut small = Harness::<1>::new();
+	let mut large = Harness::<4>::new();
+
+	while let Some(op) = Op::decode(&mut data) {
+		small.step(&op);
+		large.step(&op);
+	}
+
+	small.finish();
+	large.finish();
+}
@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+
+use super::tracked::Spec;
+
+/// An operation applied to every list of the model.
+///
+/// Indices and lengths are raw bytes, reduced by the harness against the
+/// length of the list so that both valid and out-of-bounds values occur.
+#[derive(Clone, Debug)]
+pub(crate) enum Op {
+	Push(Spec),
+	Insert(u8, Spec),
+	Extend(Vec<Spec>),
+	Pop,
+	Remove(u8),
+	SwapRemove(u8),
+	Truncate(u8),
+	Clear,
+	Retain { keep: u8, panic_at: Option<u8> },
+	Reserve(u8),
+	Clone,
+	CloneFrom(Vec<Spec>),
+	IntoIter { front: u8, back: u8 },
+}
+
+/// Takes the next byte of `data`.
+#[inline]
+fn byte(data: &mut &[u8]) -> Option<u8> {
+	let (&first, rest) = data.split_first()?;
+
+	*data = rest;
+
+	Some(first)
+}
+
+impl Spec {
+	/// Decodes an element, which panics on drop or clone one time in sixteen.
+	fn decode(data: &mut &[u8]) -> Option<Self> {
+		let value = byte(data)?;
+		let flags = byte(data)?;
+
+		Some(Self {
+			value,
+			panic_on_drop: flags % 16 == 0,
+			panic_on_clone: flags % 16 == 1,
+		})
+	}
+
+	/// Decodes up to seven elements.
+	fn decode_many(data: &mut &[u8]) -> Option<Vec<Self>> {
+		let count = byte(data)? % 8;
+
+		(0..count).map(|_| Self::decode(data)).collect()
+	}
+}
+
+impl Op {
+	/// Decodes the next operation from `data`, or returns `None` once it runs
+	/// out.
+	pub(crate) fn decode(data: &mut &[u8]) -> Option<Self> {
+		let op = match byte(data)? % 16 {
+			0..=3 => Self::Push(Spec::decode(data)?),
+			4 => Self::Insert(byte(data)?, Spec::decode(data)?),
+			5 => Self::Extend(Spec::decode_many(data)?),
+			6 => Self::Pop,
+			7 => Self::Remove(byte(data)?),
+			8 => Self::SwapRemove(byte(data)?),
+			9 => Self::Truncate(byte(data)?),
+			10 => Self::Clear,
+			11 => {
+				let keep = byte(data)?;
+				let panic_at = byte(data)?;
+
+				Self::Retain {
+					keep,
+					panic_at: (panic_at < 16).then_some(panic_at),
+				}
+			}
+			12 => Self::Reserve(byte(data)?),
+			13 => Self::Clone,
+			14 => Self::CloneFrom(Spec::decode_many(data)?),
+			_ => Self::IntoIter {
+				front: byte(data)?,
+				back: byte(data)?,
+			},
+		};
+
+		Some(op)
+	}
+}
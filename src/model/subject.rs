@@ -0,0 +1,308 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{fixed::Fixed, resizable::Resizable};
+
+use super::tracked::Tracked;
+
+/// A list driven by the model, either under test or as an oracle.
+///
+/// Every method reports a rejected value instead of panicking, so that lists
+/// with and without a fixed capacity can be compared.
+pub(crate) trait Subject: Deref<Target = [Tracked]> + Default + Clone {
+	type IntoIter: DoubleEndedIterator<Item = Tracked>;
+
+	fn capacity(&self) -> usize;
+
+	fn push(&mut self, value: Tracked) -> Result<(), Tracked>;
+
+	fn insert(&mut self, index: usize, value: Tracked) -> Result<(), Tracked>;
+
+	fn extend(&mut self, values: impl Iterator<Item = Tracked>) -> Result<(), Tracked> {
+		for value in values {
+			self.push(value)?;
+		}
+
+		Ok(())
+	}
+
+	fn pop(&mut self) -> Option<Tracked>;
+
+	fn remove(&mut self, index: usize) -> Option<Tracked>;
+
+	fn swap_remove(&mut self, index: usize) -> Option<Tracked>;
+
+	fn truncate(&mut self, len: usize);
+
+	fn clear(&mut self);
+
+	fn retain(&mut self, f: impl FnMut(&Tracked) -> bool);
+
+	fn reserve(&mut self, _additional: usize) {}
+
+	fn into_iter(self) -> Self::IntoIter;
+
+	fn from_iter(iter: Self::IntoIter) -> Self;
+}
+
+impl<const N: usize> Subject for Fixed<Tracked, N> {
+	type IntoIter = crate::fixed::IntoIter<Tracked, N>;
+
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+
+	fn push(&mut self, value: Tracked) -> Result<(), Tracked> {
+		self.try_push(value)
+	}
+
+	fn insert(&mut self, index: usize, value: Tracked) -> Result<(), Tracked> {
+		self.try_insert(index, value)
+	}
+
+	fn pop(&mut self) -> Option<Tracked> {
+		self.try_pop()
+	}
+
+	fn remove(&mut self, index: usize) -> Option<Tracked> {
+		self.try_remove(index)
+	}
+
+	fn swap_remove(&mut self, index: usize) -> Option<Tracked> {
+		self.try_swap_remove(index)
+	}
+
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	fn clear(&mut self) {
+		self.clear();
+	}
+
+	fn retain(&mut self, f: impl FnMut(&Tracked) -> bool) {
+		self.retain(f);
+	}
+
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIterator::into_iter(self)
+	}
+
+	fn from_iter(iter: Self::IntoIter) -> Self {
+		iter.collect()
+	}
+}
+
+impl<const N: usize> Subject for Resizable<Tracked, N> {
+	type IntoIter = crate::resizable::IntoIter<Tracked, N>;
+
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+
+	fn push(&mut self, value: Tracked) -> Result<(), Tracked> {
+		self.push(value);
+
+		Ok(())
+	}
+
+	fn insert(&mut self, index: usize, value: Tracked) -> Result<(), Tracked> {
+		self.try_insert(index, value)
+	}
+
+	fn extend(&mut self, values: impl Iterator<Item = Tracked>) -> Result<(), Tracked> {
+		Extend::extend(self, values);
+
+		Ok(())
+	}
+
+	fn pop(&mut self) -> Option<Tracked> {
+		self.pop()
+	}
+
+	fn remove(&mut self, index: usize) -> Option<Tracked> {
+		(index < self.len()).then(|| self.remove(index))
+	}
+
+	fn swap_remove(&mut self, index: usize) -> Option<Tracked> {
+		(index < self.len()).then(|| self.swap_remove(index))
+	}
+
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	fn clear(&mut self) {
+		self.clear();
+	}
+
+	fn retain(&mut self, f: impl FnMut(&Tracked) -> bool) {
+		self.retain(f);
+	}
+
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional);
+
+		assert!(
+			self.capacity() >= self.len() + additional,
+			"capacity after reserve"
+		);
+	}
+
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIterator::into_iter(self)
+	}
+
+	fn from_iter(iter: Self::IntoIter) -> Self {
+		iter.collect()
+	}
+}
+
+/// A `Vec` that rejects values beyond `N` elements, used as the oracle for
+/// [`Fixed`].
+#[derive(Default)]
+pub(crate) struct Bounded<const N: usize>(Vec<Tracked>);
+
+impl<const N: usize> Deref for Bounded<N> {
+	type Target = [Tracked];
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<const N: usize> Clone for Bounded<N> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+
+	fn clone_from(&mut self, source: &Self) {
+		self.0.clone_from(&source.0);
+	}
+}
+
+impl<const N: usize> Subject for Bounded<N> {
+	type IntoIter = alloc::vec::IntoIter<Tracked>;
+
+	fn capacity(&self) -> usize {
+		N
+	}
+
+	fn push(&mut self, value: Tracked) -> Result<(), Tracked> {
+		if self.0.len() == N {
+			return Err(value);
+		}
+
+		self.0.push(value);
+
+		Ok(())
+	}
+
+	fn insert(&mut self, index: usize, value: Tracked) -> Result<(), Tracked> {
+		if index > self.0.len() || self.0.len() == N {
+			return Err(value);
+		}
+
+		self.0.insert(index, value);
+
+		Ok(())
+	}
+
+	fn pop(&mut self) -> Option<Tracked> {
+		self.0.pop()
+	}
+
+	fn remove(&mut self, index: usize) -> Option<Tracked> {
+		(index < self.0.len()).then(|| self.0.remove(index))
+	}
+
+	fn swap_remove(&mut self, index: usize) -> Option<Tracked> {
+		(index < self.0.len()).then(|| self.0.swap_remove(index))
+	}
+
+	fn truncate(&mut self, len: usize) {
+		self.0.truncate(len);
+	}
+
+	fn clear(&mut self) {
+		self.0.clear();
+	}
+
+	fn retain(&mut self, f: impl FnMut(&Tracked) -> bool) {
+		self.0.retain(f);
+	}
+
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIterator::into_iter(self.0)
+	}
+
+	fn from_iter(iter: Self::IntoIter) -> Self {
+		Self(iter.collect())
+	}
+}
+
+impl Subject for Vec<Tracked> {
+	type IntoIter = alloc::vec::IntoIter<Tracked>;
+
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+
+	fn push(&mut self, value: Tracked) -> Result<(), Tracked> {
+		self.push(value);
+
+		Ok(())
+	}
+
+	fn insert(&mut self, index: usize, value: Tracked) -> Result<(), Tracked> {
+		if index > self.len() {
+			return Err(value);
+		}
+
+		self.insert(index, value);
+
+		Ok(())
+	}
+
+	fn extend(&mut self, values: impl Iterator<Item = Tracked>) -> Result<(), Tracked> {
+		Extend::extend(self, values);
+
+		Ok(())
+	}
+
+	fn pop(&mut self) -> Option<Tracked> {
+		self.pop()
+	}
+
+	fn remove(&mut self, index: usize) -> Option<Tracked> {
+		(index < self.len()).then(|| self.remove(index))
+	}
+
+	fn swap_remove(&mut self, index: usize) -> Option<Tracked> {
+		(index < self.len()).then(|| self.swap_remove(index))
+	}
+
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	fn clear(&mut self) {
+		self.clear();
+	}
+
+	fn retain(&mut self, f: impl FnMut(&Tracked) -> bool) {
+		self.retain(f);
+	}
+
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional);
+	}
+
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIterator::into_iter(self)
+	}
+
+	fn from_iter(iter: Self::IntoIter) -> Self {
+		iter.collect()
+	}
+}
@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+
+/// Generates `len` bytes from a xorshift generator seeded with `seed`.
+fn bytes(seed: u64, len: usize) -> Vec<u8> {
+	let mut state = seed | 1;
+
+	(0..len)
+		.map(|_| {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+
+			state as u8
+		})
+		.collect()
+}
+
+#[test]
+fn test_random() {
+	let (seeds, len) = if cfg!(miri) { (4, 256) } else { (64, 2048) };
+
+	for seed in 0..seeds {
+		super::run(&bytes(seed, len));
+	}
+}
+
+#[test]
+fn test_spill_boundary() {
+	// Push five elements, then pop and push back around the inline capacity.
+	let mut data = Vec::new();
+
+	for value in 0..5 {
+		data.extend([0, value, 2]);
+	}
+
+	data.extend([6, 6, 0, 9, 2, 0, 10, 2, 6, 6, 6, 6, 10]);
+
+	super::run(&data);
+}
+
+#[test]
+fn test_panicking_elements() {
+	// Push elements that panic when dropped and cloned, then clone, truncate
+	// and retain with a panicking predicate.
+	let data = [
+		0, 1, 16, 0, 2, 17, 0, 3, 2, 0, 4, 2, 0, 5, 2, 13, 14, 2, 7, 2, 8, 2, 9, 1, 11, 255, 1, 9,
+		0,
+	];
+
+	super::run(&data);
+}
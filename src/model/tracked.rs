@@ -0,0 +1,87 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+/// Bookkeeping shared by the elements of one list.
+#[derive(Debug, Default)]
+pub(crate) struct Counter {
+	live: Cell<usize>,
+	armed: Cell<bool>,
+}
+
+impl Counter {
+	/// Returns the number of elements alive.
+	#[inline]
+	pub(crate) fn live(&self) -> usize {
+		self.live.get()
+	}
+
+	/// Allows a single element to panic in `Drop` or `Clone`, or forbids it.
+	///
+	/// At most one panic is allowed at a time, as a second panic while
+	/// unwinding would abort the process.
+	#[inline]
+	pub(crate) fn arm(&self, armed: bool) {
+		self.armed.set(armed);
+	}
+
+	#[inline]
+	fn take_armed(&self) -> bool {
+		self.armed.replace(false)
+	}
+}
+
+/// The observable identity of an element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Spec {
+	pub(crate) value: u8,
+	pub(crate) panic_on_drop: bool,
+	pub(crate) panic_on_clone: bool,
+}
+
+impl Spec {
+	/// Creates an element counted by `counter`.
+	#[inline]
+	pub(crate) fn create(self, counter: &Rc<Counter>) -> Tracked {
+		counter.live.set(counter.live() + 1);
+
+		Tracked {
+			spec: self,
+			counter: counter.clone(),
+		}
+	}
+}
+
+/// An element that counts how many of its kind are alive, and may panic when
+/// dropped or cloned.
+#[derive(Debug)]
+pub(crate) struct Tracked {
+	spec: Spec,
+	counter: Rc<Counter>,
+}
+
+impl Tracked {
+	#[inline]
+	pub(crate) fn spec(&self) -> Spec {
+		self.spec
+	}
+}
+
+impl Clone for Tracked {
+	fn clone(&self) -> Self {
+		if self.spec.panic_on_clone && self.counter.take_armed() {
+			panic!("clone of {:?}", self.spec);
+		}
+
+		self.spec.create(&self.counter)
+	}
+}
+
+impl Drop for Tracked {
+	fn drop(&mut self) {
+		self.counter.live.set(self.counter.live() - 1);
+
+		if self.spec.panic_on_drop && self.counter.take_armed() {
+			panic!("drop of {:?}", self.spec);
+		}
+	}
+}
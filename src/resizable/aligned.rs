@@ -0,0 +1,142 @@
+use core::{marker::PhantomData, ptr::NonNull};
+
+use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+
+use crate::fixed::{Aligned, Alignment};
+
+use super::collection::ResizableIn;
+
+/// An allocator that raises the alignment of every allocation made through
+/// the allocator `A` to at least `Al::ALIGN` bytes.
+///
+/// A [`ResizableAligned`] list allocates through it, so its buffer stays
+/// aligned after it spills to the heap. Like any allocator, it is never asked
+/// for an allocation when the capacity is zero or the elements are zero-sized.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AlignedAlloc<Al: Alignment, A: Allocator = Global> {
+	alloc: A,
+	marker: PhantomData<Al>,
+}
+
+impl<Al: Alignment, A: Allocator> AlignedAlloc<Al, A> {
+	/// Wraps an allocator so that its allocations are aligned to `Al::ALIGN`.
+	#[inline]
+	#[must_use]
+	pub const fn new(alloc: A) -> Self {
+		Self {
+			alloc,
+			marker: PhantomData,
+		}
+	}
+
+	/// Returns a reference to the wrapped allocator.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &A {
+		&self.alloc
+	}
+
+	/// Raises the alignment of a layout to at least `Al::ALIGN`.
+	#[inline]
+	fn align(layout: Layout) -> Result<Layout, AllocError> {
+		layout.align_to(Al::ALIGN).map_err(|_| AllocError)
+	}
+
+	/// Raises the alignment of a layout that was already raised once.
+	///
+	/// # Safety
+	///
+	/// The layout must describe a block allocated through this allocator.
+	#[inline]
+	unsafe fn align_unchecked(layout: Layout) -> Layout {
+		// SAFETY: The same layout was aligned when the block was allocated.
+		unsafe { Self::align(layout).unwrap_unchecked() }
+	}
+}
+
+unsafe impl<Al: Alignment, A: Allocator> Allocator for AlignedAlloc<Al, A> {
+	#[inline]
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.alloc.allocate(Self::align(layout)?)
+	}
+
+	#[inline]
+	fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.alloc.allocate_zeroed(Self::align(layout)?)
+	}
+
+	#[inline]
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		unsafe { self.alloc.deallocate(ptr, Self::align_unchecked(layout)) }
+	}
+
+	#[inline]
+	unsafe fn grow(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<[u8]>, AllocError> {
+		let new_layout = Self::align(new_layout)?;
+
+		unsafe {
+			self.alloc
+				.grow(ptr, Self::align_unchecked(old_layout), new_layout)
+		}
+	}
+
+	#[inline]
+	unsafe fn grow_zeroed(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<[u8]>, AllocError> {
+		let new_layout = Self::align(new_layout)?;
+
+		unsafe {
+			self.alloc
+				.grow_zeroed(ptr, Self::align_unchecked(old_layout), new_layout)
+		}
+	}
+
+	#[inline]
+	unsafe fn shrink(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<[u8]>, AllocError> {
+		let new_layout = Self::align(new_layout)?;
+
+		unsafe {
+			self.alloc
+				.shrink(ptr, Self::align_unchecked(old_layout), new_layout)
+		}
+	}
+}
+
+/// A contiguous resizable list of elements of type `T` whose buffer is
+/// aligned to `Al::ALIGN` bytes, both inline and once it spills to the heap.
+///
+/// [`as_ptr`](ResizableIn::as_ptr) returns a pointer aligned to at least
+/// `Al::ALIGN` whenever the list has capacity for a non-zero-sized element.
+pub type ResizableAligned<T, const N: usize, Al, A = Global> =
+	ResizableIn<Aligned<T, N, Al>, AlignedAlloc<Al, A>>;
+
+impl<T, const N: usize, Al: Alignment> ResizableAligned<T, N, Al> {
+	/// Constructs a new, empty `ResizableAligned<T, N, Al>`.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self::new_in(AlignedAlloc::new(Global))
+	}
+
+	/// Constructs a new, empty `ResizableAligned<T, N, Al>` with at least
+	/// `capacity` capacity.
+	#[inline]
+	#[must_use]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity_in(capacity, AlignedAlloc::new(Global))
+	}
+}
@@ -0,0 +1,45 @@
+use allocator_api2::{alloc::Allocator, vec::Vec};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::collection::Resizable;
+
+impl<T, const N: usize, A: Allocator + Default> Resizable<T, N, A> {
+	/// Collects generated elements into a list, starting on the heap if
+	/// `spilled` is set so that short heap lists are generated too.
+	fn from_arbitrary_iter(iter: impl Iterator<Item = Result<T>>, spilled: bool) -> Result<Self> {
+		let mut list = if spilled {
+			Self::Heap(Vec::new_in(A::default()))
+		} else {
+			Self::default()
+		};
+
+		for value in iter {
+			list.push(value?);
+		}
+
+		Ok(list)
+	}
+}
+
+impl<'a, T: Arbitrary<'a>, const N: usize, A: Allocator + Default> Arbitrary<'a>
+	for Resizable<T, N, A>
+{
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let spilled = u.arbitrary()?;
+
+		Self::from_arbitrary_iter(u.arbitrary_iter()?, spilled)
+	}
+
+	#[inline]
+	fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+		let spilled = u.arbitrary()?;
+
+		Self::from_arbitrary_iter(u.arbitrary_take_rest_iter()?, spilled)
+	}
+
+	#[inline]
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		<bool as Arbitrary>::size_hint(depth)
+	}
+}
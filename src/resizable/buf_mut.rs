@@ -0,0 +1,59 @@
+use allocator_api2::alloc::Allocator;
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+use crate::fixed::InlineStorage;
+
+use super::collection::ResizableIn;
+
+/// Writes into the spare capacity of the list, growing it as needed.
+unsafe impl<S: InlineStorage<Item = u8>, A: Allocator> BufMut for ResizableIn<S, A> {
+	#[inline]
+	fn remaining_mut(&self) -> usize {
+		isize::MAX as usize - self.len()
+	}
+
+	#[inline]
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		let remaining = self.capacity() - self.len();
+
+		assert!(
+			cnt <= remaining,
+			"advance out of bounds: the len is {remaining} but advancing by {cnt}"
+		);
+
+		// SAFETY: The caller initialized the first `cnt` spare bytes, which fit
+		// in the capacity.
+		unsafe { self.set_len(self.len() + cnt) };
+	}
+
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		if self.capacity() == self.len() {
+			self.reserve(64);
+		}
+
+		UninitSlice::uninit(self.spare_capacity_mut())
+	}
+
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn put<B: Buf>(&mut self, mut src: B) {
+		self.reserve(src.remaining());
+
+		while src.has_remaining() {
+			let chunk = src.chunk();
+			let len = chunk.len();
+
+			self.write_bytes(chunk);
+
+			src.advance(len);
+		}
+	}
+
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn put_slice(&mut self, src: &[u8]) {
+		self.write_bytes(src);
+	}
+}
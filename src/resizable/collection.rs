@@ -1,74 +1,440 @@
-use core::ops::{Deref, DerefMut};
+use core::{
+	mem::{ManuallyDrop, MaybeUninit},
+	ops::{Deref, DerefMut},
+	ptr,
+};
 
-use alloc::vec::Vec;
+use allocator_api2::{
+	alloc::{Allocator, Global},
+	collections::TryReserveError,
+	vec::Vec,
+};
 
-use crate::fixed::Fixed;
+use crate::{
+	fixed::{FixedIn, InlineStorage},
+	traits::{List, Push},
+};
 
-use super::into_iter::IntoIter;
+use super::into_iter::IntoIterIn;
 
 macro_rules! impl_mirrored {
 	($item:expr, $list:pat => $apply:expr) => {
 		match $item {
-			Self::Fixed($list) => $apply,
+			Self::Fixed($list, _) => $apply,
 			Self::Heap($list) => $apply,
 		}
 	};
 }
 
-/// A contiguous resizable list of elements of type `T`.
-pub enum Resizable<T, const N: usize> {
-	Fixed(Fixed<T, N>),
-	Heap(Vec<T>),
+/// Panics in debug builds if allocation is forbidden on the current thread.
+#[inline]
+#[cfg_attr(feature = "alloc-guard", track_caller)]
+pub(crate) fn check_alloc() {
+	#[cfg(all(feature = "alloc-guard", debug_assertions))]
+	crate::guard::check();
+}
+
+/// Returns an error in debug builds if allocation is forbidden on the current
+/// thread, for the fallible paths that must not panic.
+#[inline]
+pub(crate) fn try_check_alloc() -> Result<(), TryReserveError> {
+	#[cfg(all(feature = "alloc-guard", debug_assertions))]
+	if crate::guard::is_alloc_forbidden() {
+		return Err(allocator_api2::collections::TryReserveErrorKind::CapacityOverflow.into());
+	}
+
+	Ok(())
+}
+
+/// Grows a heap list to fit `additional` more elements if it is full.
+///
+/// With `zeroize` enabled the old buffer is wiped rather than reallocated.
+#[inline]
+#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+pub(crate) fn reserve_heap<T, A: Allocator>(list: &mut Vec<T, A>, additional: usize, exact: bool) {
+	if list.capacity() - list.len() >= additional {
+		return;
+	}
+
+	check_alloc();
+
+	#[cfg(feature = "zeroize")]
+	super::zeroize::regrow(list, additional, exact);
+
+	#[cfg(not(feature = "zeroize"))]
+	if exact {
+		list.reserve_exact(additional);
+	} else {
+		list.reserve(additional);
+	}
+}
+
+/// Tries to grow a heap list to fit `additional` more elements if it is full.
+#[inline]
+#[cfg_attr(feature = "alloc-guard", track_caller)]
+pub(crate) fn try_reserve_heap<T, A: Allocator>(
+	list: &mut Vec<T, A>,
+	additional: usize,
+	exact: bool,
+) -> Result<(), TryReserveError> {
+	if list.capacity() - list.len() >= additional {
+		return Ok(());
+	}
+
+	try_check_alloc()?;
+
+	#[cfg(feature = "zeroize")]
+	return super::zeroize::try_regrow(list, additional, exact);
+
+	#[cfg(not(feature = "zeroize"))]
+	if exact {
+		list.try_reserve_exact(additional)
+	} else {
+		list.try_reserve(additional)
+	}
+}
+
+/// A contiguous resizable list of elements kept in the inline storage `S`
+/// until they no longer fit, at which point they are moved to a heap
+/// allocation made through the allocator `A`.
+///
+/// [`Resizable`] is the list over an inline array.
+///
+/// With `zeroize` enabled the list wipes its heap buffer when dropped, so its
+/// variants cannot be moved out of by value.
+pub enum ResizableIn<S: InlineStorage, A: Allocator = Global> {
+	Fixed(FixedIn<S>, A),
+	Heap(Vec<S::Item, A>),
+}
+
+/// The variants of a [`ResizableIn`] moved out of it, which can be matched by
+/// value whether or not the list has a destructor.
+pub(crate) enum Parts<S: InlineStorage, A: Allocator> {
+	Fixed(FixedIn<S>, A),
+	Heap(Vec<S::Item, A>),
 }
 
+/// A contiguous resizable list of elements of type `T`.
+///
+/// Elements are stored inline until they no longer fit, at which point they
+/// are moved to a heap allocation made through the allocator `A`.
+pub type Resizable<T, const N: usize, A = Global> = ResizableIn<[MaybeUninit<T>; N], A>;
+
 impl<T, const N: usize> Resizable<T, N> {
 	/// Constructs a new, empty `Resizable<T, N>`.
 	#[inline]
 	#[must_use]
 	pub const fn new() -> Self {
-		Self::Fixed(Fixed::new())
+		Self::new_in(Global)
 	}
 
 	/// Constructs a new, empty `Resizable<T, N>` with at least `capacity` capacity.
 	#[inline]
 	#[must_use]
 	pub fn with_capacity(capacity: usize) -> Self {
-		if capacity <= N {
-			Self::Fixed(Fixed::new())
+		Self::with_capacity_in(capacity, Global)
+	}
+}
+
+impl<S: InlineStorage, A: Allocator> ResizableIn<S, A> {
+	/// Constructs a new, empty list with the provided allocator.
+	#[inline]
+	#[must_use]
+	pub const fn new_in(alloc: A) -> Self {
+		Self::Fixed(FixedIn::<S>::new(), alloc)
+	}
+
+	/// Moves the contents out of the list without running its destructor.
+	#[inline]
+	pub(crate) fn into_parts(self) -> Parts<S, A> {
+		let list = ManuallyDrop::new(self);
+
+		// SAFETY: The fields are moved out of a list that is never dropped.
+		unsafe {
+			match &*list {
+				Self::Fixed(list, alloc) => Parts::Fixed(ptr::read(list), ptr::read(alloc)),
+				Self::Heap(list) => Parts::Heap(ptr::read(list)),
+			}
+		}
+	}
+
+	/// Constructs a new, empty list with at least `capacity` capacity with the
+	/// provided allocator.
+	#[inline]
+	#[must_use]
+	pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+		if capacity <= S::CAPACITY {
+			Self::Fixed(FixedIn::<S>::new(), alloc)
 		} else {
-			Self::Heap(Vec::with_capacity(capacity))
+			check_alloc();
+
+			Self::Heap(Vec::with_capacity_in(capacity, alloc))
 		}
 	}
 
+	/// Returns a reference to the underlying allocator.
+	#[inline]
+	#[must_use]
+	pub fn allocator(&self) -> &A {
+		match self {
+			Self::Fixed(_, alloc) => alloc,
+			Self::Heap(list) => list.allocator(),
+		}
+	}
+
+	/// Creates an empty heap list sharing the allocator of an inline list.
+	///
+	/// # Safety
+	///
+	/// The list must be inline, and since the allocator is duplicated, the
+	/// result must either be passed to `replace_with_heap` or be forgotten.
+	#[inline]
+	unsafe fn new_heap(&self) -> ManuallyDrop<Vec<S::Item, A>> {
+		let Self::Fixed(_, alloc) = self else {
+			unsafe { core::hint::unreachable_unchecked() }
+		};
+
+		ManuallyDrop::new(Vec::new_in(unsafe { core::ptr::read(alloc) }))
+	}
+
+	/// Moves the elements of an inline list to `heap` and replaces the list with
+	/// it. If there is a `value`, it is written at `index` and the elements
+	/// after it are moved one position to the right.
+	///
+	/// # Safety
+	///
+	/// The list must be inline, `heap` must come from `new_heap` and have room
+	/// for every element, and `index` must be at most the length of the list.
+	#[inline]
+	unsafe fn replace_with_heap(
+		&mut self,
+		heap: ManuallyDrop<Vec<S::Item, A>>,
+		index: usize,
+		value: Option<S::Item>,
+	) {
+		let Self::Fixed(list, _) = self else {
+			unsafe { core::hint::unreachable_unchecked() }
+		};
+
+		let mut heap = ManuallyDrop::into_inner(heap);
+		let len = list.len();
+		let gap = usize::from(value.is_some());
+
+		debug_assert!(
+			heap.capacity() >= len + gap,
+			"heap list too small to spill into"
+		);
+
+		unsafe {
+			let source = list.as_ptr();
+			let target = heap.as_mut_ptr();
+
+			source.copy_to_nonoverlapping(target, index);
+			source
+				.add(index)
+				.copy_to_nonoverlapping(target.add(index + gap), len - index);
+
+			if let Some(value) = value {
+				target.add(index).write(value);
+			}
+
+			list.set_len(0);
+			heap.set_len(len + gap);
+
+			#[cfg(feature = "zeroize")]
+			zeroize::Zeroize::zeroize(list.spare_capacity_mut());
+
+			// SAFETY: The inline list left behind is empty, so overwriting it
+			// without dropping it only moves the allocator out.
+			core::ptr::write(self, Self::Heap(heap));
+		}
+	}
+
+	/// Moves the elements of an inline list to the heap, reserving space for
+	/// exactly `required` elements.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub(super) fn spill(&mut self, required: usize) {
+		#[cfg(feature = "spill-stats")]
+		crate::spill::record::<S::Item>(S::CAPACITY, self.len(), required);
+
+		check_alloc();
+
+		// SAFETY: The list is inline and the heap list has room for `required`
+		// elements, which is more than the length.
+		unsafe {
+			let mut heap = self.new_heap();
+			let len = self.len();
+
+			debug_assert!(required >= len, "`required` must be at least the length");
+
+			heap.reserve(required);
+
+			self.replace_with_heap(heap, len, None);
+		}
+	}
+
+	/// Moves the elements of an inline list to the heap like `spill`, but
+	/// leaves the list untouched if the allocation fails.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn try_spill(&mut self, required: usize) -> Result<(), TryReserveError> {
+		#[cfg(feature = "spill-stats")]
+		crate::spill::record::<S::Item>(S::CAPACITY, self.len(), required);
+
+		try_check_alloc()?;
+
+		// SAFETY: The list is inline and the heap list has room for `required`
+		// elements, which is more than the length.
+		unsafe {
+			let mut heap = self.new_heap();
+			let len = self.len();
+
+			heap.try_reserve(required)?;
+
+			self.replace_with_heap(heap, len, None);
+		}
+
+		Ok(())
+	}
+
+	/// Moves the elements of a full inline list to the heap, writing `value`
+	/// at `index` as they are moved, and doubling the capacity.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn spill_insert(&mut self, index: usize, value: S::Item) {
+		let len = self.len();
+
+		#[cfg(feature = "spill-stats")]
+		crate::spill::record::<S::Item>(S::CAPACITY, len, len + 1);
+
+		check_alloc();
+
+		// SAFETY: The list is inline, `index` was checked by the caller, and the
+		// heap list has room for the new element.
+		unsafe {
+			let mut heap = self.new_heap();
+
+			heap.reserve((len * 2).max(len + 1));
+
+			self.replace_with_heap(heap, index, Some(value));
+		}
+	}
+
+	/// Moves the elements of a full inline list to the heap like
+	/// `spill_insert`, but leaves the list untouched if the allocation fails.
+	#[cold]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn try_spill_insert(&mut self, index: usize, value: S::Item) -> Result<(), S::Item> {
+		let len = self.len();
+
+		#[cfg(feature = "spill-stats")]
+		crate::spill::record::<S::Item>(S::CAPACITY, len, len + 1);
+
+		if try_check_alloc().is_err() {
+			return Err(value);
+		}
+
+		// SAFETY: The list is inline, `index` was checked by the caller, and the
+		// heap list has room for the new element.
+		unsafe {
+			let mut heap = self.new_heap();
+
+			if heap.try_reserve((len * 2).max(len + 1)).is_err() {
+				return Err(value);
+			}
+
+			self.replace_with_heap(heap, index, Some(value));
+		}
+
+		Ok(())
+	}
+
 	/// Reserves capacity for at least `additional` more elements to be inserted
 	/// in the given list.
 	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
 	pub fn reserve(&mut self, additional: usize) {
 		match self {
-			Self::Fixed(list) => {
+			Self::Fixed(list, _) => {
 				if list.capacity() - list.len() < additional {
-					let list = core::mem::take(list).to_vec_reserve(additional);
+					let required = list
+						.len()
+						.checked_add(additional)
+						.expect("capacity overflow");
 
-					*self = Self::Heap(list);
+					self.spill(required);
 				}
 			}
-			Self::Heap(list) => list.reserve(additional),
+			Self::Heap(list) => reserve_heap(list, additional, false),
 		}
 	}
 
 	/// Reserves the minimum capacity for at least `additional` more elements to
 	/// be inserted in the given list.
 	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
 	pub fn reserve_exact(&mut self, additional: usize) {
 		match self {
-			Self::Fixed(list) => {
+			Self::Fixed(list, _) => {
+				if list.capacity() - list.len() < additional {
+					let required = list
+						.len()
+						.checked_add(additional)
+						.expect("capacity overflow");
+
+					self.spill(required);
+				}
+			}
+			Self::Heap(list) => reserve_heap(list, additional, true),
+		}
+	}
+
+	/// Tries to reserve capacity for at least `additional` more elements to be
+	/// inserted in the given list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` if the capacity overflows or the allocator reports a
+	/// failure, in which case the list is left unchanged.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		match self {
+			Self::Fixed(list, _) => {
 				if list.capacity() - list.len() < additional {
-					let list = core::mem::take(list).to_vec_reserve(additional);
+					let required = list.len().saturating_add(additional);
 
-					*self = Self::Heap(list);
+					self.try_spill(required)?;
 				}
+
+				Ok(())
 			}
-			Self::Heap(list) => list.reserve_exact(additional),
+			Self::Heap(list) => try_reserve_heap(list, additional, false),
+		}
+	}
+
+	/// Tries to reserve the minimum capacity for at least `additional` more
+	/// elements to be inserted in the given list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` if the capacity overflows or the allocator reports a
+	/// failure, in which case the list is left unchanged.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		match self {
+			Self::Fixed(list, _) => {
+				if list.capacity() - list.len() < additional {
+					let required = list.len().saturating_add(additional);
+
+					self.try_spill(required)?;
+				}
+
+				Ok(())
+			}
+			Self::Heap(list) => try_reserve_heap(list, additional, true),
 		}
 	}
 
@@ -96,109 +462,239 @@ impl<T, const N: usize> Resizable<T, N> {
 	/// Returns a pointer to the first element of the list.
 	#[inline]
 	#[must_use]
-	pub fn as_ptr(&self) -> *const T {
+	pub fn as_ptr(&self) -> *const S::Item {
 		impl_mirrored!(self, list => list.as_ptr())
 	}
 
 	/// Returns a mutable pointer to the first element of the list.
 	#[inline]
 	#[must_use]
-	pub fn as_mut_ptr(&mut self) -> *mut T {
+	pub fn as_mut_ptr(&mut self) -> *mut S::Item {
 		impl_mirrored!(self, list => list.as_mut_ptr())
 	}
 
 	/// Extracts a slice containing the entire list.
 	#[inline]
 	#[must_use]
-	pub fn as_slice(&self) -> &[T] {
+	pub fn as_slice(&self) -> &[S::Item] {
 		impl_mirrored!(self, list => list.as_slice())
 	}
 
 	/// Extracts a mutable slice of the entire list.
 	#[inline]
 	#[must_use]
-	pub fn as_mut_slice(&mut self) -> &mut [T] {
+	pub fn as_mut_slice(&mut self) -> &mut [S::Item] {
 		impl_mirrored!(self, list => list.as_mut_slice())
 	}
 
+	/// Returns the remaining spare capacity of the list as a slice of
+	/// uninitialized elements.
+	#[inline]
+	#[must_use]
+	pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<S::Item>] {
+		impl_mirrored!(self, list => list.spare_capacity_mut())
+	}
+
+	/// Forces the length of the list to `len` without dropping or initializing
+	/// any elements.
+	///
+	/// # Safety
+	///
+	/// `len` must be at most the capacity, and the elements in `0..len` must be
+	/// initialized.
+	#[inline]
+	pub unsafe fn set_len(&mut self, len: usize) {
+		impl_mirrored!(self, list => unsafe { list.set_len(len) });
+	}
+
 	/// Clears the list, removing all values.
 	#[inline]
 	pub fn clear(&mut self) {
 		impl_mirrored!(self, list => list.clear());
 	}
 
+	/// Shortens the list, keeping the first `len` elements and dropping the
+	/// rest. Does nothing if `len` is not less than the current length.
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		impl_mirrored!(self, list => list.truncate(len));
+	}
+
 	/// Retains only the elements specified by the predicate.
-	pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+	pub fn retain(&mut self, f: impl FnMut(&S::Item) -> bool) {
 		impl_mirrored!(self, list => list.retain(f));
 	}
 
 	/// Retains only the elements specified by the predicate, passing a mutable reference to it.
-	pub fn retain_mut(&mut self, f: impl FnMut(&mut T) -> bool) {
+	pub fn retain_mut(&mut self, f: impl FnMut(&mut S::Item) -> bool) {
 		impl_mirrored!(self, list => list.retain_mut(f));
 	}
 
 	/// Inserts an element at position `index` within the list, shifting all
 	/// elements after it to the right.
 	#[inline]
-	pub fn insert(&mut self, index: usize, value: T) {
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn insert(&mut self, index: usize, value: S::Item) {
 		#[cold]
-		fn heap_insert<T, const N: usize>(
-			list: &mut Fixed<T, N>,
-			index: usize,
-			value: T,
-		) -> Resizable<T, N> {
-			let len = list.len();
-			let mut heap = core::mem::take(list).to_vec_reserve(len);
+		fn assert_failed(index: usize, len: usize) -> ! {
+			panic!("insertion index (is {index}) should be <= len (is {len})");
+		}
+
+		match self {
+			Self::Fixed(list, _) => {
+				if let Err(value) = list.try_insert(index, value) {
+					let len = list.len();
+
+					if index > len {
+						assert_failed(index, len);
+					}
 
-			heap.insert(index, value);
+					self.spill_insert(index, value);
+				}
+			}
+			Self::Heap(list) => {
+				reserve_heap(list, 1, false);
+				list.insert(index, value);
+			}
+		}
+	}
 
-			Resizable::Heap(heap)
+	/// Inserts an element at position `index` within the list, shifting all
+	/// elements after it to the right.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the inserted value if the list could not grow or the
+	/// index is out of bounds.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_insert(&mut self, index: usize, value: S::Item) -> Result<(), S::Item> {
+		if index > self.len() {
+			return Err(value);
 		}
 
 		match self {
-			Self::Fixed(list) => {
+			Self::Fixed(list, _) => {
 				if let Err(value) = list.try_insert(index, value) {
-					*self = heap_insert(list, index, value);
+					return self.try_spill_insert(index, value);
 				}
 			}
-			Self::Heap(list) => list.insert(index, value),
+			Self::Heap(list) => {
+				if try_reserve_heap(list, 1, false).is_err() {
+					return Err(value);
+				}
+
+				list.insert(index, value);
+			}
 		}
+
+		Ok(())
 	}
 
 	/// Appends an element to the back of the list.
 	#[inline]
-	pub fn push(&mut self, value: T) {
-		#[cold]
-		fn heap_push<T, const N: usize>(list: &mut Fixed<T, N>, value: T) -> Resizable<T, N> {
-			let len = list.len();
-			let mut heap = core::mem::take(list).to_vec_reserve(len);
-
-			heap.push(value);
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn push(&mut self, value: S::Item) {
+		match self {
+			Self::Fixed(list, _) => {
+				if let Err(value) = list.try_push(value) {
+					let len = list.len();
 
-			Resizable::Heap(heap)
+					self.spill_insert(len, value);
+				}
+			}
+			Self::Heap(list) => {
+				reserve_heap(list, 1, false);
+				list.push(value);
+			}
 		}
+	}
+
+	/// Appends an element to the back of the list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the pushed value if the list could not grow.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_push(&mut self, value: S::Item) -> Result<(), S::Item> {
+		let len = self.len();
+
+		self.try_insert(len, value)
+	}
 
+	/// Inserts an element at position `index` within the list if there is
+	/// spare capacity, without spilling to the heap or reallocating.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the inserted value if the list is full or the index
+	/// is out of bounds.
+	#[inline]
+	pub fn insert_within_capacity(&mut self, index: usize, value: S::Item) -> Result<(), S::Item> {
 		match self {
-			Self::Fixed(list) => {
-				if let Err(value) = list.try_push(value) {
-					*self = heap_push(list, value);
+			Self::Fixed(list, _) => list.try_insert(index, value),
+			Self::Heap(list) => {
+				if index > list.len() || list.len() == list.capacity() {
+					return Err(value);
 				}
+
+				list.insert(index, value);
+
+				Ok(())
 			}
-			Self::Heap(list) => list.push(value),
 		}
 	}
 
+	/// Appends an element to the back of the list if there is spare capacity,
+	/// without spilling to the heap or reallocating.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the pushed value if the list is full.
+	#[inline]
+	pub fn push_within_capacity(&mut self, value: S::Item) -> Result<(), S::Item> {
+		let len = self.len();
+
+		self.insert_within_capacity(len, value)
+	}
+
+	/// Appends every element of an iterator to the back of the list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the first element that could not be pushed if the
+	/// list could not grow. Elements before it are kept in the list, and the
+	/// rest of the iterator is dropped. Whether the capacity overflowed or the
+	/// allocator failed is not reported; [`try_reserve`](Self::try_reserve)
+	/// tells the two apart.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn try_extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) -> Result<(), S::Item> {
+		let iter = iter.into_iter();
+
+		// The hint is only an optimization, so failing to reserve it is not an error.
+		let _ = self.try_reserve(iter.size_hint().0);
+
+		// A closure would hide the caller's location from `try_push`.
+		for value in iter {
+			self.try_push(value)?;
+		}
+
+		Ok(())
+	}
+
 	/// Removes and returns the element at position `index` within the list,
 	/// shifting all elements after it to the left.
 	#[inline]
-	pub fn remove(&mut self, index: usize) -> T {
+	pub fn remove(&mut self, index: usize) -> S::Item {
 		#[cold]
 		fn assert_failed(index: usize, len: usize) -> ! {
 			panic!("removal index (is {index}) should be < len (is {len})");
 		}
 
 		match self {
-			Self::Fixed(list) => list
+			Self::Fixed(list, _) => list
 				.try_remove(index)
 				.unwrap_or_else(|| assert_failed(index, list.len())),
 
@@ -209,9 +705,9 @@ impl<T, const N: usize> Resizable<T, N> {
 	/// Removes the last element from a list and returns it, or [`None`] if it
 	/// is empty.
 	#[inline]
-	pub fn pop(&mut self) -> Option<T> {
+	pub fn pop(&mut self) -> Option<S::Item> {
 		match self {
-			Self::Fixed(list) => list.try_pop(),
+			Self::Fixed(list, _) => list.try_pop(),
 			Self::Heap(list) => list.pop(),
 		}
 	}
@@ -220,14 +716,14 @@ impl<T, const N: usize> Resizable<T, N> {
 	///
 	/// The removed element is replaced by the last element of the list.
 	#[inline]
-	pub fn swap_remove(&mut self, index: usize) -> T {
+	pub fn swap_remove(&mut self, index: usize) -> S::Item {
 		#[cold]
 		fn assert_failed(index: usize, len: usize) -> ! {
 			panic!("swap_remove index (is {index}) should be < len (is {len})");
 		}
 
 		match self {
-			Self::Fixed(list) => list
+			Self::Fixed(list, _) => list
 				.try_swap_remove(index)
 				.unwrap_or_else(|| assert_failed(index, list.len())),
 
@@ -236,22 +732,22 @@ impl<T, const N: usize> Resizable<T, N> {
 	}
 }
 
-impl<T, const N: usize> IntoIterator for Resizable<T, N> {
-	type IntoIter = IntoIter<T, N>;
-	type Item = T;
+impl<S: InlineStorage, A: Allocator> IntoIterator for ResizableIn<S, A> {
+	type IntoIter = IntoIterIn<S, A>;
+	type Item = S::Item;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
-		match self {
-			Self::Fixed(list) => IntoIter::Fixed(list.into_iter()),
-			Self::Heap(list) => IntoIter::Heap(list.into_iter()),
+		match self.into_parts() {
+			Parts::Fixed(list, _) => IntoIterIn::Fixed(list.into_iter()),
+			Parts::Heap(list) => IntoIterIn::Heap(list.into_iter()),
 		}
 	}
 }
 
-impl<'a, T, const N: usize> IntoIterator for &'a Resizable<T, N> {
-	type IntoIter = core::slice::Iter<'a, T>;
-	type Item = &'a T;
+impl<'a, S: InlineStorage, A: Allocator> IntoIterator for &'a ResizableIn<S, A> {
+	type IntoIter = core::slice::Iter<'a, S::Item>;
+	type Item = &'a S::Item;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
@@ -259,9 +755,9 @@ impl<'a, T, const N: usize> IntoIterator for &'a Resizable<T, N> {
 	}
 }
 
-impl<'a, T, const N: usize> IntoIterator for &'a mut Resizable<T, N> {
-	type IntoIter = core::slice::IterMut<'a, T>;
-	type Item = &'a mut T;
+impl<'a, S: InlineStorage, A: Allocator> IntoIterator for &'a mut ResizableIn<S, A> {
+	type IntoIter = core::slice::IterMut<'a, S::Item>;
+	type Item = &'a mut S::Item;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
@@ -269,28 +765,101 @@ impl<'a, T, const N: usize> IntoIterator for &'a mut Resizable<T, N> {
 	}
 }
 
-impl<T, const N: usize> Default for Resizable<T, N> {
+impl<S: InlineStorage, A: Allocator> List<S::Item> for ResizableIn<S, A> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+
+	#[inline]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	#[inline]
+	fn clear(&mut self) {
+		self.clear();
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: S::Item) -> Result<(), S::Item> {
+		self.try_push(value)
+	}
+
+	#[inline]
+	fn try_insert(&mut self, index: usize, value: S::Item) -> Result<(), S::Item> {
+		self.try_insert(index, value)
+	}
+
+	#[inline]
+	fn try_extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) -> Result<(), S::Item> {
+		self.try_extend(iter)
+	}
+
+	#[inline]
+	fn try_remove(&mut self, index: usize) -> Option<S::Item> {
+		(index < self.len()).then(|| self.remove(index))
+	}
+
+	#[inline]
+	fn remove(&mut self, index: usize) -> S::Item {
+		self.remove(index)
+	}
+
+	#[inline]
+	fn pop(&mut self) -> Option<S::Item> {
+		self.pop()
+	}
+}
+
+impl<S: InlineStorage, A: Allocator> Push<S::Item> for ResizableIn<S, A> {
+	#[inline]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional);
+	}
+
+	#[inline]
+	fn push(&mut self, value: S::Item) {
+		self.push(value);
+	}
+
+	#[inline]
+	fn insert(&mut self, index: usize, value: S::Item) {
+		self.insert(index, value);
+	}
+}
+
+impl<S: InlineStorage, A: Allocator + Default> Default for ResizableIn<S, A> {
 	#[inline]
 	fn default() -> Self {
-		Self::new()
+		Self::new_in(A::default())
 	}
 }
 
-impl<T, const N: usize> Extend<T> for Resizable<T, N> {
+impl<S: InlineStorage, A: Allocator> Extend<S::Item> for ResizableIn<S, A> {
 	#[inline]
-	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	fn extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) {
 		let iter = iter.into_iter();
 
 		self.reserve(iter.size_hint().0);
 
-		iter.for_each(|value| self.push(value));
+		// A closure would hide the caller's location from `push`.
+		for value in iter {
+			self.push(value);
+		}
 	}
 }
 
-impl<T, const N: usize> FromIterator<T> for Resizable<T, N> {
+impl<S: InlineStorage, A: Allocator + Default> FromIterator<S::Item> for ResizableIn<S, A> {
 	#[inline]
-	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-		let mut result = Self::new();
+	fn from_iter<I: IntoIterator<Item = S::Item>>(iter: I) -> Self {
+		let mut result = Self::default();
 
 		result.extend(iter);
 
@@ -298,26 +867,41 @@ impl<T, const N: usize> FromIterator<T> for Resizable<T, N> {
 	}
 }
 
-impl<T: Clone, const N: usize> Clone for Resizable<T, N> {
+impl<S: InlineStorage, A: Allocator + Clone> Clone for ResizableIn<S, A>
+where
+	S::Item: Clone,
+{
 	#[inline]
 	fn clone(&self) -> Self {
-		if self.len() < N {
-			Self::Fixed(self.as_slice().iter().cloned().collect())
+		let alloc = self.allocator().clone();
+
+		if self.len() <= S::CAPACITY {
+			Self::Fixed(self.as_slice().iter().cloned().collect(), alloc)
 		} else {
-			Self::Heap(self.as_slice().to_vec())
+			check_alloc();
+
+			let mut heap = Vec::with_capacity_in(self.len(), alloc);
+
+			heap.extend_from_slice(self.as_slice());
+
+			Self::Heap(heap)
 		}
 	}
 
 	#[inline]
 	fn clone_from(&mut self, source: &Self) {
-		self.clear();
+		self.truncate(source.len());
 
-		self.extend(source.iter().cloned());
+		let (init, tail) = source.split_at(self.len());
+
+		self.clone_from_slice(init);
+		self.reserve(tail.len());
+		self.extend(tail.iter().cloned());
 	}
 }
 
-impl<T, const N: usize> Deref for Resizable<T, N> {
-	type Target = [T];
+impl<S: InlineStorage, A: Allocator> Deref for ResizableIn<S, A> {
+	type Target = [S::Item];
 
 	#[inline]
 	fn deref(&self) -> &Self::Target {
@@ -325,14 +909,31 @@ impl<T, const N: usize> Deref for Resizable<T, N> {
 	}
 }
 
-impl<T, const N: usize> DerefMut for Resizable<T, N> {
+impl<S: InlineStorage, A: Allocator> DerefMut for ResizableIn<S, A> {
 	#[inline]
 	fn deref_mut(&mut self) -> &mut Self::Target {
 		self.as_mut_slice()
 	}
 }
 
-impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Resizable<T, N> {
+impl<S: InlineStorage, A: Allocator> AsRef<[S::Item]> for ResizableIn<S, A> {
+	#[inline]
+	fn as_ref(&self) -> &[S::Item] {
+		self.as_slice()
+	}
+}
+
+impl<S: InlineStorage, A: Allocator> AsMut<[S::Item]> for ResizableIn<S, A> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [S::Item] {
+		self.as_mut_slice()
+	}
+}
+
+impl<S: InlineStorage, A: Allocator> core::fmt::Debug for ResizableIn<S, A>
+where
+	S::Item: core::fmt::Debug,
+{
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		self.as_slice().fmt(f)
 	}
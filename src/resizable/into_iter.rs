@@ -1,4 +1,8 @@
-use core::iter::FusedIterator;
+use core::{iter::FusedIterator, mem::MaybeUninit};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::fixed::InlineStorage;
 
 macro_rules! impl_mirrored {
 	($item:expr, $list:pat => $apply:expr) => {
@@ -9,26 +13,43 @@ macro_rules! impl_mirrored {
 	};
 }
 
-/// An iterator that moves out of a list.
-pub enum IntoIter<T, const N: usize> {
-	Fixed(crate::fixed::IntoIter<T, N>),
-	Heap(alloc::vec::IntoIter<T>),
+/// An iterator that moves out of a [`ResizableIn`](super::ResizableIn).
+pub enum IntoIterIn<S: InlineStorage, A: Allocator = Global> {
+	Fixed(crate::fixed::IntoIterIn<S>),
+	Heap(allocator_api2::vec::IntoIter<S::Item, A>),
 }
 
-impl<T, const N: usize> IntoIter<T, N> {
+/// An iterator that moves out of a list.
+pub type IntoIter<T, const N: usize, A = Global> = IntoIterIn<[MaybeUninit<T>; N], A>;
+
+impl<S: InlineStorage, A: Allocator> IntoIterIn<S, A> {
 	/// Creates an iterator which returns no elements.
 	#[inline]
 	#[must_use]
 	pub const fn empty() -> Self {
-		Self::Fixed(crate::fixed::IntoIter::empty())
+		Self::Fixed(crate::fixed::IntoIterIn::empty())
+	}
+
+	/// Returns the remaining elements of the iterator as a slice.
+	#[inline]
+	#[must_use]
+	pub fn as_slice(&self) -> &[S::Item] {
+		impl_mirrored!(self, list => list.as_slice())
+	}
+
+	/// Returns the remaining elements of the iterator as a mutable slice.
+	#[inline]
+	#[must_use]
+	pub fn as_mut_slice(&mut self) -> &mut [S::Item] {
+		impl_mirrored!(self, list => list.as_mut_slice())
 	}
 }
 
 // We have explicit implementations for each method that could be a bottleneck
 // by having repeated calls to `next`. We don't include methods that rely on
 // other methods that are specialized, such as `for_each` which uses `fold`.
-impl<T, const N: usize> Iterator for IntoIter<T, N> {
-	type Item = T;
+impl<S: InlineStorage, A: Allocator> Iterator for IntoIterIn<S, A> {
+	type Item = S::Item;
 
 	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
@@ -125,14 +146,14 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
 	}
 }
 
-impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+impl<S: InlineStorage, A: Allocator> ExactSizeIterator for IntoIterIn<S, A> {
 	#[inline]
 	fn len(&self) -> usize {
 		impl_mirrored!(self, list => list.len())
 	}
 }
 
-impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+impl<S: InlineStorage, A: Allocator> DoubleEndedIterator for IntoIterIn<S, A> {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
 		impl_mirrored!(self, list => list.next_back())
@@ -158,4 +179,33 @@ impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
 	}
 }
 
-impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+impl<S: InlineStorage, A: Allocator> FusedIterator for IntoIterIn<S, A> {}
+
+impl<S: InlineStorage, A: Allocator> Default for IntoIterIn<S, A> {
+	#[inline]
+	fn default() -> Self {
+		Self::empty()
+	}
+}
+
+impl<S: InlineStorage, A: Allocator + Clone> Clone for IntoIterIn<S, A>
+where
+	S::Item: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		match self {
+			Self::Fixed(list) => Self::Fixed(list.clone()),
+			Self::Heap(list) => Self::Heap(list.clone()),
+		}
+	}
+}
+
+impl<S: InlineStorage, A: Allocator> core::fmt::Debug for IntoIterIn<S, A>
+where
+	S::Item: core::fmt::Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+	}
+}
@@ -0,0 +1,165 @@
+use core::{
+	alloc::Layout,
+	marker::PhantomData,
+	mem::{size_of, ManuallyDrop},
+	ptr,
+};
+
+use allocator_api2::{
+	alloc::Allocator,
+	vec::{self, Vec},
+};
+
+use super::collection::{check_alloc, Parts, Resizable};
+
+impl<T, const N: usize, A: Allocator> Resizable<T, N, A> {
+	/// Returns a list with `f` applied to each element, in order.
+	///
+	/// An inline list stays inline. A heap list whose element type keeps the
+	/// same size and alignment, and is not zero-sized, is mapped in place,
+	/// reusing its allocation and capacity. Any other heap list is moved to a
+	/// new allocation sized to its length. If `f` panics, the elements mapped
+	/// so far and those not yet mapped are all dropped.
+	#[inline]
+	#[cfg_attr(feature = "alloc-guard", track_caller)]
+	pub fn map<U>(self, f: impl FnMut(T) -> U) -> Resizable<U, N, A> {
+		match self.into_parts() {
+			Parts::Fixed(list, alloc) => Resizable::Fixed(list.map(f), alloc),
+			Parts::Heap(list)
+				if Layout::new::<T>() == Layout::new::<U>() && size_of::<T>() != 0 =>
+			{
+				Resizable::Heap(map_in_place(list, f))
+			}
+			Parts::Heap(list) => Resizable::Heap(rebuild(list, |source, target| {
+				target.extend(source.map(f));
+			})),
+		}
+	}
+
+	/// Combines two lists of equal length into a list of pairs, which is kept
+	/// inline if this list is.
+	///
+	/// # Panics
+	///
+	/// Panics if the lists have different lengths.
+	#[inline]
+	#[cfg_attr(feature = "alloc-guard", track_caller)]
+	pub fn zip<U, B: Allocator>(self, other: Resizable<U, N, B>) -> Resizable<(T, U), N, A> {
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"`zip` called on lists of different lengths"
+		);
+
+		match self.into_parts() {
+			Parts::Fixed(list, alloc) => {
+				Resizable::Fixed(list.into_iter().zip(other).collect(), alloc)
+			}
+			Parts::Heap(list) => Resizable::Heap(rebuild(list, |source, target| {
+				target.extend(source.zip(other));
+			})),
+		}
+	}
+}
+
+/// Moves the elements of a heap list into a new allocation of the same
+/// length made through its allocator, which then owns the new allocation.
+#[inline]
+#[cfg_attr(feature = "alloc-guard", track_caller)]
+fn rebuild<T, U, A: Allocator>(
+	list: Vec<T, A>,
+	build: impl FnOnce(vec::IntoIter<T, &A>, &mut Vec<U, &A>),
+) -> Vec<U, A> {
+	let (ptr, len, cap, alloc) = list.into_raw_parts_with_alloc();
+
+	let (ptr, len, cap) = {
+		// SAFETY: The parts come from a list allocated through `alloc`, which a
+		// reference to it can free.
+		let source = unsafe { Vec::from_raw_parts_in(ptr, len, cap, &alloc) };
+
+		check_alloc();
+
+		let mut target = Vec::with_capacity_in(len, &alloc);
+
+		build(source.into_iter(), &mut target);
+
+		let (ptr, len, cap, _) = target.into_raw_parts_with_alloc();
+
+		(ptr, len, cap)
+	};
+
+	// SAFETY: The buffer was allocated through a reference to `alloc`.
+	unsafe { Vec::from_raw_parts_in(ptr, len, cap, alloc) }
+}
+
+/// Maps the elements of a heap list in place.
+///
+/// `T` and `U` must have the same layout and must not be zero-sized.
+#[inline]
+fn map_in_place<T, U, A: Allocator>(list: Vec<T, A>, mut f: impl FnMut(T) -> U) -> Vec<U, A> {
+	/// Drops the elements and frees the buffer if the mapping panics.
+	struct Guard<T, U, A: Allocator> {
+		ptr: *mut T,
+		len: usize,
+		cap: usize,
+		mapped: usize,
+		alloc: ManuallyDrop<A>,
+		marker: PhantomData<U>,
+	}
+
+	impl<T, U, A: Allocator> Drop for Guard<T, U, A> {
+		fn drop(&mut self) {
+			// SAFETY: The slots before `mapped` hold mapped elements, the slot at
+			// `mapped` was moved out for the mapping that panicked, and the slots
+			// after it still hold their elements.
+			unsafe {
+				let mapped: *mut [U] = ptr::slice_from_raw_parts_mut(self.ptr.cast(), self.mapped);
+				let rest: *mut [T] = ptr::slice_from_raw_parts_mut(
+					self.ptr.add(self.mapped + 1),
+					self.len - self.mapped - 1,
+				);
+
+				mapped.drop_in_place();
+				rest.drop_in_place();
+
+				let alloc = ManuallyDrop::take(&mut self.alloc);
+
+				drop(Vec::<U, A>::from_raw_parts_in(
+					self.ptr.cast(),
+					0,
+					self.cap,
+					alloc,
+				));
+			}
+		}
+	}
+
+	let (ptr, len, cap, alloc) = list.into_raw_parts_with_alloc();
+	let mut guard = Guard {
+		ptr,
+		len,
+		cap,
+		mapped: 0,
+		alloc: ManuallyDrop::new(alloc),
+		marker: PhantomData::<U>,
+	};
+
+	while guard.mapped < len {
+		// SAFETY: The slot holds an element, which is moved out and replaced by
+		// its mapping, and `U` fits in the slot of a `T`.
+		unsafe {
+			let slot = ptr.add(guard.mapped);
+			let value = f(slot.read());
+
+			slot.cast::<U>().write(value);
+		}
+
+		guard.mapped += 1;
+	}
+
+	let mut guard = ManuallyDrop::new(guard);
+
+	// SAFETY: Every element has been mapped, and the buffer has the same layout
+	// for `U` as for `T`.
+	unsafe { Vec::from_raw_parts_in(ptr.cast(), len, cap, ManuallyDrop::take(&mut guard.alloc)) }
+}
@@ -1,11 +1,37 @@
 #[cfg(test)]
 mod test;
 
+mod aligned;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bytes")]
+mod buf_mut;
 mod collection;
 mod into_iter;
+mod map;
+#[cfg(feature = "rayon")]
+mod par_iter;
+mod raw_parts;
+mod write;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 
-pub use collection::Resizable;
-pub use into_iter::IntoIter;
+pub use aligned::{AlignedAlloc, ResizableAligned};
+/// The error returned by the fallible reservation methods.
+///
+/// With the `nightly` feature this is [`alloc::collections::TryReserveError`].
+/// On stable it is the equivalent type of `allocator-api2`, as the standard one
+/// cannot be constructed outside the standard library, so it does not convert
+/// into the standard type. It reports the same
+/// [`kind`](TryReserveError::kind), and with the `std` feature it implements
+/// [`Error`](core::error::Error), so `?` converts it into boxed errors.
+pub use allocator_api2::collections::TryReserveError;
+pub(crate) use collection::{check_alloc, reserve_heap, try_check_alloc, try_reserve_heap, Parts};
+pub use collection::{Resizable, ResizableIn};
+pub use into_iter::{IntoIter, IntoIterIn};
+#[cfg(feature = "rayon")]
+pub use par_iter::IntoParIter;
+pub use raw_parts::ResizableRawParts;
 
 #[macro_export]
 macro_rules! resizable {
@@ -0,0 +1,129 @@
+use allocator_api2::alloc::{Allocator, Global};
+use rayon::iter::{
+	plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer},
+	FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelExtend,
+	ParallelIterator,
+};
+
+use crate::fixed::par_iter::DrainProducer;
+
+use super::collection::Resizable;
+
+/// A by-value parallel iterator over the elements of a [`Resizable`].
+#[derive(Debug)]
+pub struct IntoParIter<T, const N: usize, A: Allocator = Global> {
+	list: Resizable<T, N, A>,
+}
+
+impl<T: Send, const N: usize, A: Allocator + Send> IntoParallelIterator for Resizable<T, N, A> {
+	type Iter = IntoParIter<T, N, A>;
+	type Item = T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		IntoParIter { list: self }
+	}
+}
+
+impl<'a, T: Sync, const N: usize, A: Allocator> IntoParallelIterator for &'a Resizable<T, N, A> {
+	type Iter = rayon::slice::Iter<'a, T>;
+	type Item = &'a T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.as_slice().into_par_iter()
+	}
+}
+
+impl<'a, T: Send, const N: usize, A: Allocator> IntoParallelIterator
+	for &'a mut Resizable<T, N, A>
+{
+	type Iter = rayon::slice::IterMut<'a, T>;
+	type Item = &'a mut T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.as_mut_slice().into_par_iter()
+	}
+}
+
+impl<T: Send, const N: usize, A: Allocator + Send> ParallelIterator for IntoParIter<T, N, A> {
+	type Item = T;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.list.len())
+	}
+}
+
+impl<T: Send, const N: usize, A: Allocator + Send> IndexedParallelIterator
+	for IntoParIter<T, N, A>
+{
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn len(&self) -> usize {
+		self.list.len()
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+		let len = self.list.len();
+
+		// SAFETY: The elements are handed over to the producer, which drops
+		// whatever it does not yield, so the list must forget them first. The
+		// heap buffer, if any, is freed once the callback returns.
+		unsafe {
+			match &mut self.list {
+				Resizable::Fixed(list, _) => list.set_len(0),
+				Resizable::Heap(list) => list.set_len(0),
+			}
+
+			let slice = core::slice::from_raw_parts_mut(self.list.as_mut_ptr(), len);
+
+			callback.callback(DrainProducer::new(slice))
+		}
+	}
+}
+
+impl<T: Send, const N: usize, A: Allocator> ParallelExtend<T> for Resizable<T, N, A> {
+	fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+		let chunks = par_iter.into_par_iter().collect_vec_list();
+
+		self.reserve(chunks.iter().map(alloc::vec::Vec::len).sum());
+
+		for chunk in chunks {
+			self.extend(chunk);
+		}
+	}
+}
+
+impl<'a, T: Copy + Send + Sync + 'a, const N: usize, A: Allocator> ParallelExtend<&'a T>
+	for Resizable<T, N, A>
+{
+	#[inline]
+	fn par_extend<I: IntoParallelIterator<Item = &'a T>>(&mut self, par_iter: I) {
+		self.par_extend(par_iter.into_par_iter().copied());
+	}
+}
+
+impl<T: Send, const N: usize, A: Allocator + Default> FromParallelIterator<T>
+	for Resizable<T, N, A>
+{
+	#[inline]
+	fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+		let mut list = Self::default();
+
+		list.par_extend(par_iter);
+
+		list
+	}
+}
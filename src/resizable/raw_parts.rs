@@ -0,0 +1,102 @@
+use allocator_api2::{
+	alloc::{Allocator, Global},
+	vec::Vec,
+};
+
+use crate::fixed::{FixedIn, InlineStorage, RawParts};
+
+use super::collection::{Parts, ResizableIn};
+
+/// The raw components of a [`ResizableIn`], as returned by
+/// [`ResizableIn::into_raw_parts`].
+pub enum ResizableRawParts<S: InlineStorage, A: Allocator = Global> {
+	/// The inline storage, the number of initialized elements at its start,
+	/// and the allocator.
+	Fixed(S, usize, A),
+	/// The pointer, length and capacity of a heap allocation made through the
+	/// allocator, which owns it.
+	Heap(RawParts<S::Item>, A),
+}
+
+impl<S: InlineStorage, A: Allocator> ResizableIn<S, A> {
+	/// Decomposes the list into its raw components without dropping any
+	/// elements.
+	///
+	/// A heap list is left allocated, and it is up to the caller to free it,
+	/// usually by passing the parts back to
+	/// [`from_raw_parts`](ResizableIn::from_raw_parts).
+	#[inline]
+	#[must_use]
+	pub fn into_raw_parts(self) -> ResizableRawParts<S, A> {
+		match self.into_parts() {
+			Parts::Fixed(mut list, alloc) => {
+				let len = list.len();
+
+				// SAFETY: The elements stay in the storage, which is returned
+				// along with their number.
+				unsafe { list.set_len(0) };
+
+				ResizableRawParts::Fixed(list.into_storage(), len, alloc)
+			}
+			Parts::Heap(list) => {
+				let (ptr, len, cap, alloc) = list.into_raw_parts_with_alloc();
+
+				ResizableRawParts::Heap(RawParts { ptr, len, cap }, alloc)
+			}
+		}
+	}
+
+	/// Creates a list directly from its raw components.
+	///
+	/// # Safety
+	///
+	/// For inline parts, the first `len` elements of the storage must be
+	/// initialized and `len` must be at most its capacity.
+	///
+	/// For heap parts, the same requirements as
+	/// [`Vec::from_raw_parts_in`] apply: `ptr` must have been allocated
+	/// through the allocator for exactly `cap` elements, and its first `len`
+	/// elements must be initialized.
+	#[inline]
+	#[must_use]
+	pub unsafe fn from_raw_parts(parts: ResizableRawParts<S, A>) -> Self {
+		match parts {
+			ResizableRawParts::Fixed(inner, len, alloc) => {
+				debug_assert!(len <= S::CAPACITY, "`len` must be at most the capacity");
+
+				let mut list = FixedIn::from_storage(inner);
+
+				unsafe { list.set_len(len) };
+
+				Self::Fixed(list, alloc)
+			}
+			ResizableRawParts::Heap(RawParts { ptr, len, cap }, alloc) => {
+				Self::Heap(unsafe { Vec::from_raw_parts_in(ptr, len, cap, alloc) })
+			}
+		}
+	}
+
+	/// Consumes the list and leaks its buffer, returning a mutable slice of its
+	/// elements.
+	///
+	/// An inline list is moved to the heap first, so the slice points into a
+	/// heap allocation that is never freed. An empty list or one of zero-sized
+	/// elements allocates nothing, and its slice is dangling instead.
+	#[inline]
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub fn leak<'a>(mut self) -> &'a mut [S::Item]
+	where
+		A: 'a,
+	{
+		if let Self::Fixed(list, _) = &self {
+			self.spill(list.len());
+		}
+
+		let Parts::Heap(list) = self.into_parts() else {
+			// SAFETY: The list was spilled above.
+			unsafe { core::hint::unreachable_unchecked() }
+		};
+
+		list.leak()
+	}
+}
@@ -1,7 +1,19 @@
+use core::{alloc::Layout, cell::Cell, panic::AssertUnwindSafe, ptr::NonNull};
+
 use alloc::boxed::Box;
+use allocator_api2::alloc::{AllocError, Allocator, Global};
 
 use crate::resizable::Resizable;
 
+/// Counts its drops in a shared counter, tagged with a value to tell it apart.
+struct Counted<'a>(&'a Cell<usize>, u64);
+
+impl Drop for Counted<'_> {
+	fn drop(&mut self) {
+		self.0.set(self.0.get() + 1);
+	}
+}
+
 #[test]
 fn test_push_elements() {
 	let mut resizable = Resizable::<u64, 2>::new();
@@ -104,3 +116,533 @@ fn test_into_iter() {
 	assert_eq!(iter.next(), None);
 	assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_niche() {
+	use core::mem::size_of;
+
+	use crate::fixed::Fixed;
+
+	assert_eq!(size_of::<Resizable<u64, 4>>(), size_of::<Fixed<u64, 4>>());
+}
+
+#[test]
+fn test_aligned() {
+	use crate::{fixed::Align32, resizable::ResizableAligned};
+
+	let mut resizable = ResizableAligned::<f32, 4, Align32>::new();
+
+	resizable.extend([0.0; 4]);
+
+	assert!(matches!(resizable, ResizableAligned::Fixed(..)));
+	assert_eq!(resizable.as_ptr() as usize % 32, 0);
+
+	for len in 5..=64 {
+		resizable.push(len as f32);
+
+		assert!(matches!(resizable, ResizableAligned::Heap(_)));
+		assert_eq!(resizable.as_ptr() as usize % 32, 0);
+	}
+
+	let clone = resizable.clone();
+
+	assert_eq!(clone.as_ptr() as usize % 32, 0);
+	assert_eq!(clone.as_slice(), resizable.as_slice());
+	assert_eq!(
+		ResizableAligned::<u8, 2, Align32>::with_capacity(3).as_ptr() as usize % 32,
+		0
+	);
+}
+
+#[test]
+fn test_raw_parts() {
+	use crate::resizable::ResizableRawParts;
+
+	let mut resizable = Resizable::<Box<u64>, 2>::new();
+
+	resizable.push(Box::new(1));
+
+	let ResizableRawParts::Fixed(inner, len, alloc) = resizable.into_raw_parts() else {
+		panic!("expected inline parts");
+	};
+
+	assert_eq!(len, 1);
+
+	let mut resizable =
+		unsafe { Resizable::from_raw_parts(ResizableRawParts::Fixed(inner, len, alloc)) };
+
+	resizable.extend([2, 3].map(Box::new));
+
+	let ResizableRawParts::Heap(parts, alloc) = resizable.into_raw_parts() else {
+		panic!("expected heap parts");
+	};
+
+	assert_eq!(parts.len, 3);
+	assert!(parts.cap >= 3);
+
+	let resizable: Resizable<Box<u64>, 2> =
+		unsafe { Resizable::from_raw_parts(ResizableRawParts::Heap(parts, alloc)) };
+
+	assert_eq!(resizable.as_slice(), &[1, 2, 3].map(Box::new));
+}
+
+#[test]
+fn test_leak() {
+	use core::cell::RefCell;
+
+	use alloc::vec::Vec;
+
+	struct Live<'a>(&'a RefCell<Vec<(NonNull<u8>, Layout)>>);
+
+	unsafe impl Allocator for Live<'_> {
+		fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			let block = Global.allocate(layout)?;
+
+			self.0.borrow_mut().push((block.cast(), layout));
+
+			Ok(block)
+		}
+
+		unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+			self.0.borrow_mut().retain(|&(live, _)| live != ptr);
+
+			unsafe { Global.deallocate(ptr, layout) };
+		}
+	}
+
+	// A list over the global allocator leaks into a `'static` slice.
+	fn _leak_static(list: Resizable<u64, 4>) -> &'static mut [u64] {
+		list.leak()
+	}
+
+	let live = RefCell::new(Vec::new());
+	let mut resizable = Resizable::<u64, 4, _>::new_in(Live(&live));
+
+	resizable.extend([1, 2]);
+
+	let leaked = resizable.leak();
+
+	leaked[0] = 3;
+
+	assert_eq!(leaked, &[3, 2]);
+	assert_eq!(live.borrow()[0].0.as_ptr(), leaked.as_mut_ptr().cast());
+
+	let mut resizable = Resizable::<u64, 1, _>::new_in(Live(&live));
+
+	resizable.extend([1, 2, 3]);
+	resizable.pop();
+
+	assert_eq!(resizable.leak(), &[1, 2]);
+	assert!(Resizable::<u64, 1, _>::new_in(Live(&live))
+		.leak()
+		.is_empty());
+	assert_eq!(live.borrow().len(), 2);
+
+	for (ptr, layout) in live.take() {
+		unsafe { Global.deallocate(ptr, layout) };
+	}
+}
+
+#[test]
+fn test_map() {
+	let resizable = Resizable::<u32, 2>::from_iter([1, 2]);
+
+	assert!(matches!(
+		resizable.clone().map(f32::from_bits),
+		Resizable::Fixed(..)
+	));
+	assert_eq!(resizable.map(u64::from).as_slice(), &[1, 2]);
+
+	let resizable = Resizable::<u32, 2>::from_iter(1..=5);
+	let ptr = resizable.as_ptr();
+	let mapped = resizable.map(|x| x as i32 - 3);
+
+	assert_eq!(mapped.as_ptr().cast(), ptr);
+	assert_eq!(mapped.as_slice(), &[-2, -1, 0, 1, 2]);
+	assert_eq!(
+		mapped.map(|x| alloc::format!("{x}")).as_slice(),
+		["-2", "-1", "0", "1", "2"]
+	);
+
+	let drops = Cell::new(0);
+	let resizable = Resizable::<Counted, 2>::from_iter((0..6).map(|value| Counted(&drops, value)));
+	let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+		resizable.map(|item| {
+			assert!(item.1 != 3, "mapping panicked");
+
+			item
+		})
+	}));
+
+	assert!(result.is_err());
+	assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_zip() {
+	let inline =
+		Resizable::<u8, 4>::from_iter([1, 2]).zip(Resizable::<char, 4>::from_iter("ab".chars()));
+
+	assert!(matches!(inline, Resizable::Fixed(..)));
+	assert_eq!(inline.as_slice(), &[(1, 'a'), (2, 'b')]);
+
+	let heap = Resizable::<u8, 1>::from_iter([1, 2]).zip(Resizable::<u16, 1>::from_iter([3, 4]));
+
+	assert_eq!(heap.as_slice(), &[(1, 3), (2, 4)]);
+}
+
+#[test]
+#[should_panic = "capacity overflow"]
+fn test_reserve_overflow() {
+	let mut resizable = Resizable::<u8, 16>::from_iter(0..10);
+
+	resizable.reserve(usize::MAX - 5);
+}
+
+#[test]
+#[should_panic = "capacity overflow"]
+fn test_reserve_exact_overflow() {
+	let mut resizable = Resizable::<u8, 16>::from_iter(0..10);
+
+	resizable.reserve_exact(usize::MAX - 5);
+}
+
+#[test]
+fn test_allocator() {
+	struct Counting<'a>(&'a Cell<usize>);
+
+	unsafe impl Allocator for Counting<'_> {
+		fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			self.0.set(self.0.get() + 1);
+
+			Global.allocate(layout)
+		}
+
+		unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+			unsafe { Global.deallocate(ptr, layout) };
+		}
+	}
+
+	let count = Cell::new(0);
+	let mut resizable = Resizable::<u64, 2, _>::new_in(Counting(&count));
+
+	resizable.push(1);
+	resizable.push(2);
+
+	assert_eq!(count.get(), 0);
+
+	resizable.push(3);
+
+	assert_eq!(count.get(), 1);
+	assert!(matches!(resizable, Resizable::Heap(_)));
+
+	let resizable = Resizable::<u64, 2, _>::with_capacity_in(4, Counting(&count));
+
+	assert_eq!(count.get(), 2);
+	assert!(resizable.capacity() >= 4);
+}
+
+#[test]
+fn test_try_reserve() {
+	let mut resizable = Resizable::<u64, 2>::new();
+
+	resizable.push(1);
+	resizable.push(2);
+
+	assert!(resizable.try_reserve(usize::MAX).is_err());
+	assert!(resizable.try_reserve_exact(usize::MAX).is_err());
+	assert!(matches!(resizable, Resizable::Fixed(..)));
+
+	assert_eq!(resizable.try_reserve(2), Ok(()));
+	assert!(resizable.capacity() >= 4);
+	assert!(resizable.try_reserve(usize::MAX).is_err());
+
+	assert_eq!(resizable.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn test_try_push_failing() {
+	struct Failing;
+
+	unsafe impl Allocator for Failing {
+		fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			Err(AllocError)
+		}
+
+		unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {}
+	}
+
+	let mut resizable = Resizable::<u64, 2, _>::new_in(Failing);
+
+	assert_eq!(resizable.try_push(1), Ok(()));
+	assert_eq!(resizable.try_insert(0, 2), Ok(()));
+	assert_eq!(resizable.try_push(3), Err(3));
+	assert_eq!(resizable.try_insert(0, 4), Err(4));
+	assert_eq!(resizable.try_extend([5, 6]), Err(5));
+
+	assert!(matches!(resizable, Resizable::Fixed(..)));
+	assert_eq!(resizable.as_slice(), &[2, 1]);
+}
+
+#[test]
+fn test_try_insert_elements() {
+	let mut resizable = Resizable::<u64, 2>::new();
+
+	assert_eq!(resizable.try_insert(1, 1), Err(1));
+	assert_eq!(resizable.try_insert(0, 1), Ok(()));
+	assert_eq!(resizable.try_insert(0, 2), Ok(()));
+	assert_eq!(resizable.try_insert(1, 3), Ok(()));
+	assert_eq!(resizable.try_insert(4, 4), Err(4));
+	assert_eq!(resizable.try_extend([4, 5]), Ok(()));
+
+	assert_eq!(resizable.as_slice(), &[2, 3, 1, 4, 5]);
+}
+
+#[test]
+fn test_into_iter_slices() {
+	for len in [2, 4] {
+		let resizable = Resizable::<u64, 2>::from_iter(1..=len);
+		let mut iter = resizable.into_iter();
+
+		assert_eq!(iter.next(), Some(1));
+
+		iter.as_mut_slice()[0] = 5;
+
+		assert_eq!(iter.as_slice()[0], 5);
+		assert_eq!(iter.clone().count(), iter.len());
+		assert_eq!(
+			alloc::format!("{:?}", iter.clone()),
+			alloc::format!("IntoIter({:?})", iter.as_slice())
+		);
+	}
+
+	assert_eq!(crate::resizable::IntoIter::<u64, 2>::default().len(), 0);
+}
+
+#[test]
+fn test_insert_spill() {
+	for index in 0..=3 {
+		let mut resizable = Resizable::<Box<u64>, 3>::from_iter((0..3).map(Box::new));
+
+		resizable.insert(index, Box::new(9));
+
+		let mut expected = alloc::vec![0, 1, 2];
+
+		expected.insert(index, 9);
+
+		assert!(matches!(resizable, Resizable::Heap(_)));
+		assert!(resizable.capacity() >= 6);
+		assert!(resizable.iter().map(|value| **value).eq(expected));
+	}
+}
+
+#[test]
+#[should_panic = "insertion index (is 4) should be <= len (is 3)"]
+fn test_insert_spill_out_of_bounds() {
+	let mut resizable = Resizable::<u64, 3>::from_iter(0..3);
+
+	resizable.insert(4, 9);
+}
+
+#[test]
+fn test_clone() {
+	let resizable = Resizable::<u64, 2>::from_iter([1, 2]);
+
+	assert!(matches!(resizable.clone(), Resizable::Fixed(..)));
+
+	let mut heap = Resizable::<u64, 2>::with_capacity(8);
+	let pointer = heap.as_ptr();
+
+	heap.clone_from(&resizable);
+
+	assert!(matches!(heap, Resizable::Heap(_)));
+	assert_eq!(heap.as_ptr(), pointer);
+	assert_eq!(heap.as_slice(), &[1, 2]);
+
+	let mut fixed = Resizable::<u64, 2>::from_iter([3]);
+
+	fixed.clone_from(&resizable);
+
+	assert!(matches!(fixed, Resizable::Fixed(..)));
+	assert_eq!(fixed.as_slice(), &[1, 2]);
+
+	fixed.clone_from(&Resizable::from_iter(0..5));
+
+	assert_eq!(fixed.as_slice(), &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_par_iter() {
+	use alloc::{sync::Arc, vec::Vec};
+
+	use rayon::iter::{
+		IndexedParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+	};
+
+	for len in [2, 64] {
+		let mut resizable = (0..len).into_par_iter().collect::<Resizable<u64, 4>>();
+
+		assert!(resizable.iter().copied().eq(0..len));
+
+		(&mut resizable)
+			.into_par_iter()
+			.for_each(|value| *value += 1);
+
+		assert_eq!(
+			(&resizable).into_par_iter().sum::<u64>(),
+			len * (len + 1) / 2
+		);
+		assert!(resizable
+			.into_par_iter()
+			.collect::<Vec<_>>()
+			.into_iter()
+			.eq(1..=len));
+	}
+
+	let mut resizable = Resizable::<u64, 4>::from_iter([1, 2]);
+
+	resizable.par_extend(&[3, 4, 5]);
+
+	assert_eq!(resizable.as_slice(), &[1, 2, 3, 4, 5]);
+
+	let shared = Arc::new(());
+	let resizable = Resizable::<_, 4>::from_iter(core::iter::repeat_n(shared.clone(), 16));
+	let taken = resizable
+		.into_par_iter()
+		.with_max_len(1)
+		.skip(2)
+		.take(3)
+		.collect::<Vec<_>>();
+
+	assert_eq!(Arc::strong_count(&shared), 4);
+
+	drop(taken);
+
+	assert_eq!(Arc::strong_count(&shared), 1);
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn test_arbitrary() {
+	use arbitrary::{Arbitrary, Unstructured};
+
+	for (spilled, len) in [(0, 1), (1, 1), (0, 3)] {
+		let mut bytes = alloc::vec![spilled];
+
+		for value in 0..len {
+			bytes.extend([1, value]);
+		}
+
+		bytes.push(0);
+
+		let resizable = Resizable::<u8, 2>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+		assert_eq!(resizable.len(), usize::from(len));
+		assert_eq!(
+			matches!(resizable, Resizable::Heap(_)),
+			spilled == 1 || len > 2
+		);
+	}
+}
+
+#[test]
+fn test_write() {
+	use core::fmt::Write;
+
+	let mut resizable = Resizable::<u8, 4>::new();
+
+	write!(resizable, "{}", 12).unwrap();
+
+	assert!(matches!(resizable, Resizable::Fixed(..)));
+
+	write!(resizable, "-{}", 345).unwrap();
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+	assert_eq!(resizable.as_slice(), b"12-345");
+
+	#[cfg(feature = "std")]
+	{
+		std::io::Write::write_all(&mut resizable, b"def").unwrap();
+
+		assert_eq!(resizable.as_slice(), b"12-345def");
+	}
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn test_buf_mut() {
+	use bytes::BufMut;
+
+	let mut resizable = Resizable::<u8, 4>::new();
+
+	resizable.put_u16(0x0102);
+	resizable.put(&b"abc"[..]);
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+	assert_eq!(resizable.as_slice(), b"\x01\x02abc");
+
+	let mut resizable = Resizable::<u8, 2>::from_iter([1, 2]);
+
+	assert_ne!(resizable.chunk_mut().len(), 0);
+	assert!(matches!(resizable, Resizable::Heap(_)));
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn test_zeroize() {
+	use zeroize::{Zeroize, Zeroizing};
+
+	struct Wiped<'a>(&'a Cell<usize>);
+
+	unsafe impl Allocator for Wiped<'_> {
+		fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			Global.allocate(layout)
+		}
+
+		unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+			let bytes = unsafe { core::slice::from_raw_parts(ptr.as_ptr(), layout.size()) };
+
+			assert!(bytes.iter().all(|&byte| byte == 0));
+
+			self.0.set(self.0.get() + 1);
+
+			unsafe { Global.deallocate(ptr, layout) };
+		}
+	}
+
+	let freed = Cell::new(0);
+	let mut resizable = Resizable::<u8, 2, _>::new_in(Wiped(&freed));
+
+	resizable.extend(1..=3);
+
+	assert!(matches!(resizable, Resizable::Heap(_)));
+
+	let capacity = resizable.capacity();
+
+	resizable.extend(4..=capacity as u8 + 1);
+	resizable.reserve_exact(16);
+	resizable.insert(0, 0);
+
+	assert_eq!(freed.get(), 2);
+	assert_eq!(resizable[..4], [0, 1, 2, 3]);
+
+	resizable.pop();
+	resizable.zeroize();
+
+	assert!(resizable.is_empty());
+	assert!((resizable.spare_capacity_mut().iter()).all(|slot| unsafe { slot.assume_init() } == 0));
+
+	resizable.extend([1, 2, 3]);
+	drop(Zeroizing::new(resizable));
+
+	assert_eq!(freed.get(), 3);
+
+	let mut resizable = Resizable::<u8, 2, _>::with_capacity_in(8, Wiped(&freed));
+
+	resizable.extend(1..=5);
+	resizable.pop();
+	drop(resizable);
+
+	assert_eq!(freed.get(), 4);
+}
@@ -0,0 +1,59 @@
+use core::fmt;
+
+use allocator_api2::alloc::Allocator;
+
+use crate::fixed::InlineStorage;
+
+use super::collection::ResizableIn;
+
+impl<S: InlineStorage<Item = u8>, A: Allocator> ResizableIn<S, A> {
+	/// Appends every byte of `bytes`, growing the list as needed.
+	#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+	pub(super) fn write_bytes(&mut self, bytes: &[u8]) {
+		self.reserve(bytes.len());
+
+		let len = self.len();
+
+		// SAFETY: The bytes fit in the reserved spare capacity.
+		unsafe {
+			self.as_mut_ptr()
+				.add(len)
+				.copy_from_nonoverlapping(bytes.as_ptr(), bytes.len());
+			self.set_len(len + bytes.len());
+		}
+	}
+}
+
+impl<S: InlineStorage<Item = u8>, A: Allocator> fmt::Write for ResizableIn<S, A> {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.write_bytes(s.as_bytes());
+
+		Ok(())
+	}
+}
+
+/// Appends every byte, growing the list as needed.
+///
+/// The bytes are read back through [`Cursor::new(list)`](std::io::Cursor::new).
+#[cfg(feature = "std")]
+impl<S: InlineStorage<Item = u8>, A: Allocator> std::io::Write for ResizableIn<S, A> {
+	#[inline]
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.write_bytes(buf);
+
+		Ok(buf.len())
+	}
+
+	#[inline]
+	fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+		self.write_bytes(buf);
+
+		Ok(())
+	}
+
+	#[inline]
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
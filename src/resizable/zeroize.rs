@@ -0,0 +1,116 @@
+use core::{alloc::Layout, mem::ManuallyDrop, ptr};
+
+use allocator_api2::{alloc::Allocator, collections::TryReserveError, vec::Vec};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::fixed::InlineStorage;
+
+use super::collection::ResizableIn;
+
+/// Zeroes the elements, drops them and then zeroes the whole buffer, whether
+/// inline or on the heap, including its spare capacity.
+impl<S: InlineStorage, A: Allocator> Zeroize for ResizableIn<S, A>
+where
+	S::Item: Zeroize,
+{
+	#[inline]
+	fn zeroize(&mut self) {
+		self.iter_mut().zeroize();
+		self.clear();
+		self.spare_capacity_mut().zeroize();
+	}
+}
+
+/// Dropping a list zeroes its whole buffer once the elements are gone, whether
+/// inline or on the heap. Iterating a heap list by value hands its buffer to
+/// the iterator of the vector, which frees it without wiping it.
+impl<S: InlineStorage, A: Allocator> ZeroizeOnDrop for ResizableIn<S, A> {}
+
+impl<S: InlineStorage, A: Allocator> Drop for ResizableIn<S, A> {
+	#[inline]
+	fn drop(&mut self) {
+		// An inline list is wiped by its own destructor.
+		if let Self::Heap(list) = self {
+			list.clear();
+			list.spare_capacity_mut().zeroize();
+		}
+	}
+}
+
+/// Moves the elements of `list` to a new buffer with room for at least
+/// `additional` more, zeroing and freeing the old one instead of reallocating
+/// it in place.
+pub(super) fn try_regrow<T, A: Allocator>(
+	list: &mut Vec<T, A>,
+	additional: usize,
+	exact: bool,
+) -> Result<(), TryReserveError> {
+	let Some(capacity) = grown_capacity(list, additional, exact) else {
+		// Overflows without allocating.
+		return list.try_reserve(additional);
+	};
+
+	let mut grown = ManuallyDrop::new(Vec::<T, &A>::new_in(list.allocator()));
+
+	grown.try_reserve_exact(capacity)?;
+
+	let (target, capacity) = (grown.as_mut_ptr(), grown.capacity());
+	let (source, len, old_capacity) = (list.as_mut_ptr(), list.len(), list.capacity());
+
+	unsafe {
+		source.copy_to_nonoverlapping(target, len);
+		list.set_len(0);
+		list.spare_capacity_mut().zeroize();
+
+		// SAFETY: The new buffer was allocated by the same allocator, which is
+		// moved over while the old vector is forgotten so it is dropped once.
+		let allocator = ptr::read(list.allocator());
+		let old = ptr::replace(
+			list,
+			Vec::from_raw_parts_in(target, len, capacity, allocator),
+		);
+
+		core::mem::forget(old);
+
+		drop(Vec::<T, &A>::from_raw_parts_in(
+			source,
+			0,
+			old_capacity,
+			list.allocator(),
+		));
+	}
+
+	Ok(())
+}
+
+/// Like [`try_regrow`], but panics on capacity overflow and aborts on
+/// allocation failure, like [`Vec::reserve`].
+#[cfg_attr(any(feature = "spill-stats", feature = "alloc-guard"), track_caller)]
+pub(super) fn regrow<T, A: Allocator>(list: &mut Vec<T, A>, additional: usize, exact: bool) {
+	if try_regrow(list, additional, exact).is_ok() {
+		return;
+	}
+
+	let layout = grown_capacity(list, additional, exact)
+		.and_then(|capacity| Layout::array::<T>(capacity).ok());
+
+	match layout {
+		Some(layout) => alloc::alloc::handle_alloc_error(layout),
+		None => panic!("capacity overflow"),
+	}
+}
+
+/// Returns the capacity to grow to, or `None` if it overflows.
+fn grown_capacity<T, A: Allocator>(
+	list: &Vec<T, A>,
+	additional: usize,
+	exact: bool,
+) -> Option<usize> {
+	let required = list.len().checked_add(additional)?;
+
+	if exact {
+		Some(required)
+	} else {
+		Some(required.max(list.capacity().saturating_mul(2)))
+	}
+}
@@ -0,0 +1,60 @@
+use core::{
+	panic::Location,
+	sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// A record of a list moving its elements from inline storage to the heap.
+#[derive(Clone, Copy, Debug)]
+pub struct SpillEvent {
+	/// The location of the call that caused the spill.
+	pub location: &'static Location<'static>,
+	/// The name of the element type of the list.
+	pub type_name: &'static str,
+	/// The inline capacity of the list.
+	pub inline_capacity: usize,
+	/// The number of elements in the list when it spilled.
+	pub len: usize,
+	/// The number of elements the list needed room for.
+	pub required: usize,
+}
+
+/// A function called on every spill.
+pub type SpillHook = fn(&SpillEvent);
+
+static HOOK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Registers a hook to be called on every spill, replacing the previous one.
+#[inline]
+pub fn set_hook(hook: SpillHook) {
+	HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+/// Unregisters the current hook and returns it, if any.
+#[inline]
+pub fn take_hook() -> Option<SpillHook> {
+	let hook = HOOK.swap(core::ptr::null_mut(), Ordering::AcqRel);
+
+	// SAFETY: Only `SpillHook` pointers are ever stored.
+	(!hook.is_null()).then(|| unsafe { core::mem::transmute::<*mut (), SpillHook>(hook) })
+}
+
+/// Reports a spill of a list of `T` to the registered hook, if any.
+#[track_caller]
+pub(crate) fn record<T>(inline_capacity: usize, len: usize, required: usize) {
+	let hook = HOOK.load(Ordering::Acquire);
+
+	if hook.is_null() {
+		return;
+	}
+
+	// SAFETY: Only `SpillHook` pointers are ever stored.
+	let hook = unsafe { core::mem::transmute::<*mut (), SpillHook>(hook) };
+
+	hook(&SpillEvent {
+		location: Location::caller(),
+		type_name: core::any::type_name::<T>(),
+		inline_capacity,
+		len,
+		required,
+	});
+}
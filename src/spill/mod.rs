@@ -0,0 +1,9 @@
+#[cfg(test)]
+mod test;
+
+mod event;
+mod stats;
+
+pub(crate) use event::record;
+pub use event::{set_hook, take_hook, SpillEvent, SpillHook};
+pub use stats::{SiteStats, SpillStats};
@@ -0,0 +1,116 @@
+use core::panic::Location;
+
+use alloc::collections::BTreeMap;
+
+use super::event::SpillEvent;
+
+const BUCKET_COUNT: usize = usize::BITS as usize + 1;
+
+/// Spill statistics of a single call site and element type.
+#[derive(Clone, Debug)]
+pub struct SiteStats {
+	/// The number of spills.
+	pub count: u64,
+	/// The largest number of elements a list held when it spilled.
+	///
+	/// This is the length at the moment of the spill, not the largest length
+	/// the list reached afterwards, which is not tracked.
+	pub peak_spill_len: usize,
+	/// The largest number of elements a spill needed room for.
+	pub peak_required: usize,
+	/// The number of spills by the number of elements the list held, where
+	/// bucket `0` holds `0` and bucket `i` holds `2^(i - 1)..2^i`.
+	pub histogram: [u64; BUCKET_COUNT],
+}
+
+impl SiteStats {
+	/// Returns the histogram bucket that `len` falls into.
+	#[inline]
+	#[must_use]
+	pub const fn bucket(len: usize) -> usize {
+		(usize::BITS - len.leading_zeros()) as usize
+	}
+
+	fn record(&mut self, event: &SpillEvent) {
+		self.count += 1;
+		self.peak_spill_len = self.peak_spill_len.max(event.len);
+		self.peak_required = self.peak_required.max(event.required);
+		self.histogram[Self::bucket(event.len)] += 1;
+	}
+}
+
+impl Default for SiteStats {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			count: 0,
+			peak_spill_len: 0,
+			peak_required: 0,
+			histogram: [0; BUCKET_COUNT],
+		}
+	}
+}
+
+type Site = (&'static Location<'static>, &'static str);
+
+/// Spill statistics aggregated by call site and element type.
+///
+/// The statistics are usually fed from a [`SpillHook`](super::SpillHook) that
+/// forwards every event to [`SpillStats::record`].
+#[derive(Clone, Debug, Default)]
+pub struct SpillStats {
+	sites: BTreeMap<Site, SiteStats>,
+}
+
+impl SpillStats {
+	/// Constructs a new, empty `SpillStats`.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			sites: BTreeMap::new(),
+		}
+	}
+
+	/// Adds a spill event to the statistics of its call site.
+	#[inline]
+	pub fn record(&mut self, event: &SpillEvent) {
+		let site = (event.location, event.type_name);
+
+		self.sites.entry(site).or_default().record(event);
+	}
+
+	/// Returns the statistics of a call site and element type, if it spilled.
+	#[inline]
+	#[must_use]
+	pub fn get(
+		&self,
+		location: &'static Location<'static>,
+		type_name: &'static str,
+	) -> Option<&SiteStats> {
+		self.sites.get(&(location, type_name))
+	}
+
+	/// Returns an iterator over the call sites, element types and statistics.
+	#[inline]
+	pub fn iter(
+		&self,
+	) -> impl Iterator<Item = (&'static Location<'static>, &'static str, &SiteStats)> {
+		self.sites
+			.iter()
+			.map(|(&(location, type_name), stats)| (location, type_name, stats))
+	}
+
+	/// Returns the total number of spills across all call sites.
+	#[inline]
+	#[must_use]
+	pub fn total(&self) -> u64 {
+		self.sites.values().map(|stats| stats.count).sum()
+	}
+
+	/// Removes all recorded statistics.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.sites.clear();
+	}
+}
@@ -0,0 +1,86 @@
+use core::{
+	panic::Location,
+	sync::atomic::{AtomicU32, AtomicUsize, Ordering},
+};
+
+use crate::{
+	compact::Compact,
+	resizable::Resizable,
+	spill::{SiteStats, SpillEvent, SpillStats},
+};
+
+#[derive(Clone)]
+struct Marker;
+
+static SPILL_COUNT: AtomicUsize = AtomicUsize::new(0);
+static SPILL_LINE: AtomicU32 = AtomicU32::new(0);
+
+fn count_marker_spills(event: &SpillEvent) {
+	if event.type_name == core::any::type_name::<Marker>() {
+		SPILL_COUNT.fetch_add(1, Ordering::Relaxed);
+		SPILL_LINE.store(event.location.line(), Ordering::Relaxed);
+	}
+}
+
+#[test]
+fn test_hook() {
+	super::set_hook(count_marker_spills);
+
+	let mut resizable = Resizable::<Marker, 2>::new();
+
+	resizable.push(Marker);
+	resizable.push(Marker);
+
+	assert_eq!(SPILL_COUNT.load(Ordering::Relaxed), 0);
+
+	let line = line!() + 1;
+	resizable.push(Marker);
+
+	assert_eq!(SPILL_COUNT.load(Ordering::Relaxed), 1);
+	assert_eq!(SPILL_LINE.load(Ordering::Relaxed), line);
+
+	let mut compact = Compact::<Marker, 2>::new();
+
+	let line = line!() + 1;
+	compact.extend([Marker, Marker, Marker]);
+
+	assert_eq!(SPILL_COUNT.load(Ordering::Relaxed), 2);
+	assert_eq!(SPILL_LINE.load(Ordering::Relaxed), line);
+
+	assert!(super::take_hook().is_some());
+	assert!(super::take_hook().is_none());
+}
+
+#[test]
+fn test_stats() {
+	let mut stats = SpillStats::new();
+	let location = Location::caller();
+	let mut event = SpillEvent {
+		location,
+		type_name: "u8",
+		inline_capacity: 4,
+		len: 4,
+		required: 5,
+	};
+
+	stats.record(&event);
+
+	event.len = 6;
+	event.required = 12;
+
+	stats.record(&event);
+
+	let site = stats.get(location, "u8").expect("Site should be recorded");
+
+	assert_eq!(site.count, 2);
+	assert_eq!(site.peak_spill_len, 6);
+	assert_eq!(site.peak_required, 12);
+	assert_eq!(site.histogram[SiteStats::bucket(4)], 2);
+	assert_eq!(site.histogram[SiteStats::bucket(12)], 0);
+	assert_eq!(stats.total(), 2);
+	assert!(stats.get(location, "u16").is_none());
+
+	stats.clear();
+
+	assert_eq!(stats.total(), 0);
+}
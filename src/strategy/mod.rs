@@ -0,0 +1,85 @@
+//! [`proptest`] strategies for generating lists.
+
+#[cfg(test)]
+mod test;
+
+use alloc::vec::Vec;
+
+use proptest::{
+	arbitrary::{any, any_with, Arbitrary},
+	collection::{vec, SizeRange, VecStrategy},
+	strategy::{Map, Strategy},
+};
+
+use crate::{fixed::Fixed, resizable::Resizable};
+
+/// A strategy for generating [`Fixed`] lists, created by [`fixed`].
+pub type FixedStrategy<S, const N: usize> =
+	Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> Fixed<<S as Strategy>::Value, N>>;
+
+/// A strategy for generating [`Resizable`] lists, created by [`resizable`].
+pub type ResizableStrategy<S, const N: usize> = Map<
+	(VecStrategy<S>, proptest::bool::Any),
+	fn((Vec<<S as Strategy>::Value>, bool)) -> Resizable<<S as Strategy>::Value, N>,
+>;
+
+/// Creates a strategy for generating [`Fixed`] lists with a length in `size`
+/// and elements from `element`.
+///
+/// # Panics
+///
+/// Panics if `size` allows lengths greater than `N`.
+pub fn fixed<S: Strategy, const N: usize>(
+	element: S,
+	size: impl Into<SizeRange>,
+) -> FixedStrategy<S, N> {
+	let size = size.into();
+
+	assert!(
+		size.end_incl() <= N,
+		"`size` allows lengths greater than `N`"
+	);
+
+	vec(element, size).prop_map(Fixed::from_iter)
+}
+
+/// Creates a strategy for generating [`Resizable`] lists with a length in
+/// `size` and elements from `element`.
+///
+/// Lists of every length are generated both inline and on the heap, except for
+/// lists longer than `N`, which are always on the heap.
+pub fn resizable<S: Strategy, const N: usize>(
+	element: S,
+	size: impl Into<SizeRange>,
+) -> ResizableStrategy<S, N> {
+	(vec(element, size), any::<bool>()).prop_map(|(values, spilled)| {
+		if spilled || values.len() > N {
+			Resizable::Heap(values.into_iter().collect())
+		} else {
+			Resizable::from_iter(values)
+		}
+	})
+}
+
+/// Generates lists of any length up to `N`.
+impl<T: Arbitrary, const N: usize> Arbitrary for Fixed<T, N> {
+	type Parameters = T::Parameters;
+	type Strategy = FixedStrategy<T::Strategy, N>;
+
+	#[inline]
+	fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+		fixed(any_with::<T>(args), 0..=N)
+	}
+}
+
+/// Generates lists of any length up to `2 * N + 1`, so that inline lists,
+/// full lists and spilled lists are all generated.
+impl<T: Arbitrary, const N: usize> Arbitrary for Resizable<T, N> {
+	type Parameters = T::Parameters;
+	type Strategy = ResizableStrategy<T::Strategy, N>;
+
+	#[inline]
+	fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+		resizable(any_with::<T>(args), 0..=N * 2 + 1)
+	}
+}
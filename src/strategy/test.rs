@@ -0,0 +1,53 @@
+use proptest::{
+	arbitrary::any,
+	proptest,
+	strategy::{Strategy, ValueTree},
+	test_runner::TestRunner,
+};
+
+use crate::{fixed::Fixed, resizable::Resizable};
+
+proptest! {
+	#[test]
+	fn test_fixed(fixed in super::fixed::<_, 4>(any::<u8>(), 1..=3)) {
+		assert!((1..=3).contains(&fixed.len()));
+	}
+
+	#[test]
+	fn test_fixed_any(fixed in any::<Fixed<u8, 4>>()) {
+		assert!(fixed.len() <= 4);
+	}
+
+	#[test]
+	fn test_resizable_any(resizable in any::<Resizable<u8, 4>>()) {
+		assert!(resizable.len() <= 9);
+		assert!(matches!(resizable, Resizable::Heap(_)) || resizable.len() <= 4);
+	}
+}
+
+#[test]
+#[should_panic = "`size` allows lengths greater than `N`"]
+fn test_fixed_oversized() {
+	let _ = super::fixed::<_, 4>(any::<u8>(), 0..=5);
+}
+
+#[test]
+fn test_resizable_variants() {
+	let strategy = any::<Resizable<u8, 4>>();
+	let mut runner = TestRunner::deterministic();
+	let mut seen = [[false; 2]; 3];
+
+	for _ in 0..1000 {
+		let resizable = strategy.new_tree(&mut runner).unwrap().current();
+		let len = match resizable.len() {
+			0..4 => 0,
+			4 => 1,
+			_ => 2,
+		};
+		let spilled = matches!(resizable, Resizable::Heap(_));
+
+		seen[len][usize::from(spilled)] = true;
+	}
+
+	assert_eq!(seen, [[true; 2], [true; 2], [false, true]]);
+}
@@ -0,0 +1,108 @@
+use core::ops::DerefMut;
+
+/// A contiguous list of elements of type `T`.
+///
+/// Every list can attempt to grow, which fails when the list is at capacity
+/// or, for lists that allocate, when the allocation fails.
+pub trait List<T>: DerefMut<Target = [T]> {
+	/// Returns the number of elements in the list.
+	fn len(&self) -> usize;
+
+	/// Returns `true` if the list contains no elements.
+	#[inline]
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the total number of elements the list can hold without
+	/// reallocating.
+	fn capacity(&self) -> usize;
+
+	/// Extracts a slice containing the entire list.
+	#[inline]
+	fn as_slice(&self) -> &[T] {
+		self
+	}
+
+	/// Shortens the list, keeping the first `len` elements and dropping the
+	/// rest. Does nothing if `len` is not less than the current length.
+	fn truncate(&mut self, len: usize);
+
+	/// Clears the list, removing all values.
+	#[inline]
+	fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Appends an element to the back of the list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the pushed value if the list could not grow.
+	fn try_push(&mut self, value: T) -> Result<(), T>;
+
+	/// Inserts an element at position `index` within the list, shifting all
+	/// elements after it to the right.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the inserted value if the list could not grow or the
+	/// index is out of bounds.
+	fn try_insert(&mut self, index: usize, value: T) -> Result<(), T>;
+
+	/// Appends every element of an iterator to the back of the list.
+	///
+	/// # Errors
+	///
+	/// Returns `Err` with the first element that could not be pushed. Elements
+	/// before it are kept in the list.
+	#[inline]
+	fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), T> {
+		for value in iter {
+			self.try_push(value)?;
+		}
+
+		Ok(())
+	}
+
+	/// Removes and returns the element at position `index` within the list,
+	/// shifting all elements after it to the left, or returns [`None`] if the
+	/// index is out of bounds.
+	fn try_remove(&mut self, index: usize) -> Option<T>;
+
+	/// Removes and returns the element at position `index` within the list,
+	/// shifting all elements after it to the left.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds.
+	#[inline]
+	fn remove(&mut self, index: usize) -> T {
+		let len = self.len();
+
+		self.try_remove(index)
+			.unwrap_or_else(|| panic!("removal index (is {index}) should be < len (is {len})"))
+	}
+
+	/// Removes the last element from a list and returns it, or [`None`] if it
+	/// is empty.
+	fn pop(&mut self) -> Option<T>;
+}
+
+/// A list that grows to fit any number of elements.
+pub trait Push<T>: List<T> + Extend<T> {
+	/// Reserves capacity for at least `additional` more elements to be inserted
+	/// in the given list.
+	fn reserve(&mut self, additional: usize);
+
+	/// Appends an element to the back of the list.
+	fn push(&mut self, value: T);
+
+	/// Inserts an element at position `index` within the list, shifting all
+	/// elements after it to the right.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is greater than the length of the list.
+	fn insert(&mut self, index: usize, value: T);
+}
@@ -0,0 +1,8 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test;
+
+mod list;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use list::{List, Push};
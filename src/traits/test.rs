@@ -0,0 +1,67 @@
+use core::mem::MaybeUninit;
+
+use alloc::vec::Vec;
+
+use crate::{
+	compact::Compact,
+	fixed::{Fixed, FixedSlice},
+	resizable::Resizable,
+	traits::{List, Push},
+};
+
+fn fill_list<L: List<u64>>(list: &mut L) {
+	assert_eq!(list.try_push(1), Ok(()));
+	assert_eq!(list.try_insert(0, 2), Ok(()));
+	assert_eq!(list.try_insert(3, 3), Err(3));
+	assert_eq!(list.try_extend([3, 4]), Ok(()));
+
+	assert_eq!(&**list, &[2, 1, 3, 4]);
+
+	assert_eq!(list.try_remove(4), None);
+	assert_eq!(list.remove(1), 1);
+	assert_eq!(list.pop(), Some(4));
+
+	list.truncate(1);
+
+	assert_eq!(&**list, &[2]);
+
+	list.clear();
+
+	assert!(list.is_empty());
+}
+
+fn grow_list<L: Push<u64>>(list: &mut L) {
+	list.reserve(8);
+
+	assert!(list.capacity() >= 8);
+
+	list.push(1);
+	list.insert(0, 2);
+	list.extend([3, 4]);
+
+	assert_eq!(&**list, &[2, 1, 3, 4]);
+}
+
+#[test]
+fn test_list() {
+	fill_list(&mut Fixed::<u64, 4>::new());
+	fill_list(&mut FixedSlice::new(&mut [MaybeUninit::uninit(); 4]));
+	fill_list(&mut Resizable::<u64, 2>::new());
+	fill_list(&mut Compact::<u64, 2>::new());
+	fill_list(&mut Vec::new());
+}
+
+#[test]
+fn test_push() {
+	grow_list(&mut Resizable::<u64, 2>::new());
+	grow_list(&mut Compact::<u64, 2>::new());
+	grow_list(&mut Vec::new());
+}
+
+#[test]
+fn test_fixed_full() {
+	let mut fixed = Fixed::<u64, 2>::new();
+
+	assert_eq!(fixed.try_extend([1, 2, 3]), Err(3));
+	assert_eq!(List::len(&fixed), 2);
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use super::list::{List, Push};
+
+impl<T> List<T> for Vec<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+
+	#[inline]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: T) -> Result<(), T> {
+		if self.try_reserve(1).is_err() {
+			return Err(value);
+		}
+
+		self.push(value);
+
+		Ok(())
+	}
+
+	#[inline]
+	fn try_insert(&mut self, index: usize, value: T) -> Result<(), T> {
+		if index > self.len() || self.try_reserve(1).is_err() {
+			return Err(value);
+		}
+
+		self.insert(index, value);
+
+		Ok(())
+	}
+
+	#[inline]
+	fn try_remove(&mut self, index: usize) -> Option<T> {
+		(index < self.len()).then(|| self.remove(index))
+	}
+
+	#[inline]
+	fn remove(&mut self, index: usize) -> T {
+		self.remove(index)
+	}
+
+	#[inline]
+	fn pop(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T> Push<T> for Vec<T> {
+	#[inline]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional);
+	}
+
+	#[inline]
+	fn push(&mut self, value: T) {
+		self.push(value);
+	}
+
+	#[inline]
+	fn insert(&mut self, index: usize, value: T) {
+		self.insert(index, value);
+	}
+}